@@ -0,0 +1,81 @@
+//! Uses every macro of `bit_fiddler` from a `no_std` crate.
+//!
+//! Building this crate is the check: any expansion going through `std`
+//! fails to resolve here. Build with `--features debug-checks` to also
+//! cover the validating expansions.
+
+#![no_std]
+
+use bit_fiddler::{
+    all_unset, any_set, assign, bits, checked_is_set, checked_mask, checked_put_bits, checked_set,
+    checked_toggle, checked_unset, count_set, count_unset, first_set, first_unset, from_positions,
+    get_bits, is_set, last_set, last_unset, mask, max_bits, missing_bits, none_set, nth_set, ones,
+    put_bits, set, test_and_set, test_and_toggle, test_and_unset, to_positions, toggle, unset,
+    zeros, BitError,
+};
+
+pub const IRQ_MASK: u32 = mask!([4..8], u32) | set!(0, u32, [0, 2]);
+pub const WIDTH: usize = max_bits!(type = u64) + max_bits!(IRQ_MASK);
+
+pub fn update(regs: &mut [u16; 2], channel: usize) -> bool {
+    set!(in regs[0], u16, [channel, rev 0]);
+    unset!(in regs[1], [start = channel, count = 2]);
+    toggle!(in regs[1], rev [..; step = 4]);
+    put_bits!(in regs[1], u16, [8..12] = channel);
+    assign!(in regs[0], u16, rev [1..3], channel > 4);
+    let data = regs[1];
+    regs[1] = assign!(data, [12, 14], regs[0] == 0);
+    let [ctrl, data] = *regs;
+    is_set!(ctrl, u16, [0..=3]) || is_set!(data, rev channel) || any_set!(ctrl, [4, 6..8])
+        || none_set!(data, u16, rev [start = 0, count = 4]) && all_unset!(data, channel)
+}
+
+pub fn claim(slots: &mut [u32; 4], slot: usize) -> bool {
+    let prev = test_and_set!(in slots[0], u32, [slot..slot + 2]) | test_and_unset!(in slots[1], rev [..8]);
+    test_and_toggle!(in slots[2], u32, rev 0) && !test_and_set!(in slots[3], slot) && prev == 0
+}
+
+pub const RESET: u32 = bits!(IRQ_MASK, u32 { unset [..4], toggle rev 0, put [8..12] = 0b_1010 });
+
+pub fn configure(reg: &mut u32, mode: u32) -> u32 {
+    bits!(in *reg, u32 { set [0..3], unset 5, toggle rev 1, put [8..12] = mode });
+    let value = *reg;
+    bits!(value, { set rev [..2], put rev [4..8] = mode })
+}
+
+pub fn count(reg: u64) -> u32 {
+    count_set!(reg) + count_set!(reg, u64, rev [..8]) + count_unset!(reg, [0, 8..16])
+}
+
+pub fn find(reg: u16, n: u32) -> Option<u32> {
+    let free = first_unset!(reg, [4..12]).or(last_unset!(reg, u16, rev [..4]));
+    let busy = first_set!(reg, rev 0).or(last_set!(reg)).or(nth_set!(reg, u16, n, [8..]));
+    free.or(busy)
+}
+
+pub fn positions(reg: u32) -> u32 {
+    ones!(reg).chain(ones!(reg, u32, rev [..8]).rev()).sum::<u32>() + zeros!(reg, [4..]).len() as u32
+}
+
+pub fn remap(reg: u16, buf: &mut [u32; 16]) -> Result<u16, BitError> {
+    let count = to_positions!(reg, u16 => buf) + to_positions!(reg, rev [..4] => &mut buf[8..]);
+    from_positions!(u16, rev &buf[..count % 16])
+}
+
+pub fn missing(status: u32) -> u32 {
+    missing_bits!(status, u32, [0, 3, 7..12]) | missing_bits!(status, rev 0)
+}
+
+pub fn read(reg: u32, channel: u32) -> u8 {
+    get_bits!(reg, u32, [channel * 4..channel * 4 + 4] as u8) + get_bits!(reg, rev [..4] as u8)
+}
+
+pub fn checked_update(bitmap: &mut u8, pos: usize) -> Result<bool, BitError> {
+    checked_set!(in *bitmap, u8, pos)?;
+    checked_unset!(in *bitmap, rev [pos..8])?;
+    checked_toggle!(in *bitmap, [0, 2])?;
+    let _ = checked_mask!([pos..; step = 2], u8)?;
+    checked_put_bits!(in *bitmap, u8, rev [..2] = 0b_10)?;
+    let bitmap = *bitmap;
+    checked_is_set!(bitmap, u8, [..pos])
+}
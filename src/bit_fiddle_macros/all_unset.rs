@@ -0,0 +1,27 @@
+/// Macro for checking if all bits are unset among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set), which
+/// checks that all of them are set, and returns a `bool`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With no bits selected, like `[4..4]`, the result is `true`.
+/// [`none_set!`](crate::none_set) is the same check under another name.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::all_unset;
+///
+/// let bitmap: u16 = 0b_1000_0000_0010_0000;
+///
+/// assert!(all_unset!(bitmap, [8..15]));
+/// assert!(!all_unset!(bitmap, u16, [1, 5, 7]));
+/// assert!(all_unset!(bitmap, rev [start = 1, count = 8]));
+/// assert!(all_unset!(0b_0111_1111, u8, rev 0));
+/// ```
+#[macro_export]
+macro_rules! all_unset {
+    ($( $args: tt )+) => {
+        $crate::__none_set!(all_unset, $( $args )+)
+    };
+}
@@ -0,0 +1,50 @@
+/// Macro for checking if any bit is set among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set), which
+/// checks that all of them are set, and returns a `bool`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With no bits selected, like `[4..4]`, the result is `false`.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::any_set;
+///
+/// let bitmap: u16 = 0b_1000_0000_0010_0000;
+///
+/// assert!(any_set!(bitmap, [1, 5, 7]));
+/// assert!(!any_set!(bitmap, u16, [8..15]));
+/// assert!(any_set!(bitmap, rev [start = 0, count = 2]));
+/// assert!(!any_set!(0b_0111_1111, u8, rev 0));
+/// ```
+#[macro_export]
+macro_rules! any_set {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(any_set, (bitmap ^ bitmap), [$( $sel )*])) != (bitmap ^ bitmap)
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(any_set, (bitmap ^ bitmap), rev $( $sel )+)) != (bitmap ^ bitmap)
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(any_set, (bitmap ^ bitmap), $bit_pos)) != (bitmap ^ bitmap)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        (($bitmap as $ty) & $crate::__mask!(any_set, (0 as $ty), $( $sel )+)) != (0 as $ty)
+    };
+}
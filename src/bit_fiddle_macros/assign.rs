@@ -0,0 +1,233 @@
+/// Macro for setting or unsetting single, multiple or range of bits
+/// according to a `bool`.
+/// It accepts the patterns of [`set!`](crate::set), followed by the condition.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The selected bits are set when the condition is `true` and unset when
+/// it is `false`. The result is computed without branching on the condition.
+///
+/// Like with `set!`, the bitmap can be passed with `in` to change it in place,
+/// which returns the bitmap as it was before the change.
+///
+/// ```
+/// # use bit_fiddler::assign;
+/// let mut cfg: u32 = 0b_0000_1111;
+///
+/// assign!(in cfg, u32, [4..8], true);
+/// assert_eq!(cfg, 0b_1111_1111);
+///
+/// let enabled = false;
+/// assign!(in cfg, 0, enabled);
+/// assert_eq!(cfg, 0b_1111_1110);
+///
+/// let res = assign!(cfg, u32, rev [start = 0, count = 2], true);
+/// assert_eq!(res, 0b_1100_0000_0000_0000_0000_0000_1111_1110);
+///
+/// let res = assign!(cfg, [..4], 1 > 2);
+/// assert_eq!(res, 0b_1111_0000);
+/// ```
+#[macro_export]
+macro_rules! assign {
+    ($bitmap: tt, [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::assign!(
+                @assign (bitmap ^ bitmap),
+                bitmap,
+                $crate::__mask!(assign, (bitmap ^ bitmap), [$( $sel )*]),
+                $flag
+            )
+        }
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::assign!(
+                @assign (bitmap ^ bitmap),
+                bitmap,
+                $crate::__mask!(assign, (bitmap ^ bitmap), rev [$( $sel )*]),
+                $flag
+            )
+        }
+    };
+
+    ($bitmap: tt, rev $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::assign!(
+                @assign (bitmap ^ bitmap),
+                bitmap,
+                $crate::__mask!(assign, (bitmap ^ bitmap), rev $bit_pos),
+                $flag
+            )
+        }
+    };
+
+    (in $bitmap: expr, [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(assign, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign zero, *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, rev [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(assign, zero, rev [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign zero, *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, rev $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(assign, zero, rev $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign zero, *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*], $flag: expr) => {
+        $crate::assign!(
+            @assign (0 as $ty),
+            ($bitmap as $ty),
+            $crate::__mask!(assign, (0 as $ty), [$( $sel )*]),
+            $flag
+        )
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*], $flag: expr) => {
+        $crate::assign!(
+            @assign (0 as $ty),
+            ($bitmap as $ty),
+            $crate::__mask!(assign, (0 as $ty), rev [$( $sel )*]),
+            $flag
+        )
+    };
+
+    ($bitmap: tt, $ty: ty, rev $bit_pos: expr, $flag: expr) => {
+        $crate::assign!(
+            @assign (0 as $ty),
+            ($bitmap as $ty),
+            $crate::__mask!(assign, (0 as $ty), rev $bit_pos),
+            $flag
+        )
+    };
+
+    ($bitmap: tt, $ty: ty, $bit_pos: expr, $flag: expr) => {
+        $crate::assign!(
+            @assign (0 as $ty),
+            ($bitmap as $ty),
+            $crate::__mask!(assign, (0 as $ty), $bit_pos),
+            $flag
+        )
+    };
+
+    (in $bitmap: expr, $ty: ty, [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let mask = $crate::__mask!(assign, (0 as $ty), [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign (0 as $ty), *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, rev [$( $sel: tt )*], $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let mask = $crate::__mask!(assign, (0 as $ty), rev [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign (0 as $ty), *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, rev $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let mask = $crate::__mask!(assign, (0 as $ty), rev $bit_pos);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign (0 as $ty), *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let mask = $crate::__mask!(assign, (0 as $ty), $bit_pos);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign (0 as $ty), *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    // Single bits come after the typed arms. Here, the type of a typed call
+    // would be taken as the bit and the selector as the condition, which
+    // fails to parse for selectors like `rev [start = 0, count = 2]`.
+    ($bitmap: tt, $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::assign!(
+                @assign (bitmap ^ bitmap),
+                bitmap,
+                $crate::__mask!(assign, (bitmap ^ bitmap), $bit_pos),
+                $flag
+            )
+        }
+    };
+
+    (in $bitmap: expr, $bit_pos: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let flag = $flag;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(assign, zero, $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::assign!(@assign zero, *bitmap, mask, flag);
+            prev
+        }
+    };
+
+    // Sets the bits of `$mask` in `$bitmap` to `$flag`. The bits to put there
+    // are all ones or all zeros, picked by shifting by 0 or the full width.
+    (@assign $zero: tt, $bitmap: expr, $mask: expr, $flag: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let fill = $crate::__mask!(@low $zero, ($flag as usize) * $crate::max_bits!($zero));
+            bitmap ^ ((bitmap ^ fill) & $mask)
+        }
+    };
+}
@@ -0,0 +1,55 @@
+/// Shared implementation of `ones!` and `zeros!`.
+///
+/// `$name` is the macro being expanded and `$which` is `set` or `unset`.
+/// The rest are the arguments passed to the macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bit_positions {
+    ($name: ident, $which: ident, $bitmap: tt) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__bit_positions!(@iter (bitmap ^ bitmap), false, $crate::__find_bit!(@$which bitmap))
+        }
+    };
+
+    ($name: ident, $which: ident, $bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!($name, (bitmap ^ bitmap), [$( $sel )*]);
+            $crate::__bit_positions!(@iter (bitmap ^ bitmap), false, $crate::__find_bit!(@$which bitmap) & mask)
+        }
+    };
+
+    ($name: ident, $which: ident, $bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!($name, (bitmap ^ bitmap), rev $( $sel )+);
+            $crate::__bit_positions!(@iter (bitmap ^ bitmap), true, $crate::__find_bit!(@$which bitmap) & mask)
+        }
+    };
+
+    ($name: ident, $which: ident, $bitmap: tt, $ty: ty) => {
+        $crate::__bit_positions!(@iter (0 as $ty), false, $crate::__find_bit!(@$which ($bitmap as $ty)))
+    };
+
+    ($name: ident, $which: ident, $bitmap: tt, $ty: ty, [$( $sel: tt )*]) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), [$( $sel )*]);
+            $crate::__bit_positions!(@iter (0 as $ty), false, $crate::__find_bit!(@$which ($bitmap as $ty)) & mask)
+        }
+    };
+
+    ($name: ident, $which: ident, $bitmap: tt, $ty: ty, rev $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), rev $( $sel )+);
+            $crate::__bit_positions!(@iter (0 as $ty), true, $crate::__find_bit!(@$which ($bitmap as $ty)) & mask)
+        }
+    };
+
+    (@iter $zero: tt, $rev: expr, $bits: expr) => {
+        $crate::__private::bit_positions(($bits) as u128, $crate::max_bits!($zero), $rev)
+    };
+}
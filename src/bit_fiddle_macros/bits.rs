@@ -0,0 +1,134 @@
+/// Macro for applying several operations to a bitmap at once.
+/// Operations are separated by commas and are one of
+///
+/// - `set` followed by the patterns of [`set!`](crate::set),
+/// - `unset` followed by the patterns of [`unset!`](crate::unset),
+/// - `toggle` followed by the patterns of [`toggle!`](crate::toggle),
+/// - `put` followed by the patterns of [`put_bits!`](crate::put_bits).
+///
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The operations are applied in order, as if the macros were called one
+/// after another. They are first combined into a mask of bits to keep and
+/// a mask of bits to flip, so the bitmap is then read and written only once.
+/// With `in`, the bitmap is changed in place and the bitmap as it was before
+/// the change is returned. Otherwise, the new bitmap is returned.
+///
+/// ```
+/// # use bit_fiddler::bits;
+/// let mut reg: u32 = 0b_1111_0000_0010_0000;
+/// let mode = 0b_0110;
+///
+/// let prev = bits!(in reg, u32 { set [0..3], unset 5, toggle rev 1, put [8..12] = mode });
+/// assert_eq!(prev, 0b_1111_0000_0010_0000);
+/// assert_eq!(reg, 0b_0100_0000_0000_0000_1111_0110_0000_0111);
+///
+/// let res = bits!(reg, { unset [..], set rev [..2] });
+/// assert_eq!(res, 0xc000_0000);
+/// ```
+///
+/// Like with `set!`, the type can be left out, as in `bits!(in reg, { ... })`.
+/// With a type, values to `put` are converted to it with `as`. Without a type,
+/// they must already have the type of the bitmap.
+#[macro_export]
+macro_rules! bits {
+    ($bitmap: tt, { $( $ops: tt )* }) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::bits!(@apply keep, flip, (bitmap ^ bitmap), []; $( $ops )*);
+            (bitmap & keep) ^ flip
+        }
+    };
+
+    (in $bitmap: expr, { $( $ops: tt )* }) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            $crate::bits!(@apply keep, flip, zero, []; $( $ops )*);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = (prev & keep) ^ flip;
+            prev
+        }
+    };
+
+    ($bitmap: tt, $ty: ty { $( $ops: tt )* }) => {
+        {
+            let bitmap = $bitmap as $ty;
+            $crate::bits!(@apply keep, flip, (0 as $ty), [as $ty]; $( $ops )*);
+            (bitmap & keep) ^ flip
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty { $( $ops: tt )* }) => {
+        {
+            $crate::bits!(@apply keep, flip, (0 as $ty), [as $ty]; $( $ops )*);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = (prev & keep) ^ flip;
+            prev
+        }
+    };
+
+    // Declares `$keep` and `$flip` such that `(bitmap & $keep) ^ $flip`
+    // applies all of the operations. `$cast` converts values to `put`.
+    (@apply $keep: ident, $flip: ident, $zero: tt, $cast: tt; $( $ops: tt )*) => {
+        let mut $keep = !$zero;
+        let mut $flip = $zero;
+        $crate::bits!(@ops $keep, $flip, $zero, $cast; $( $ops )*);
+    };
+
+    (@ops $keep: ident, $flip: ident, $zero: tt, $cast: tt;) => {};
+    (@ops $keep: ident, $flip: ident, $zero: tt, $cast: tt; $op: ident $( $rest: tt )*) => {
+        $crate::bits!(@split $keep, $flip, $zero, $cast, $op, []; $( $rest )*);
+    };
+
+    // Collects the tokens of one operation, up to the next top level comma.
+    (@split $keep: ident, $flip: ident, $zero: tt, $cast: tt, $op: ident, [$( $sel: tt )*]; , $( $rest: tt )*) => {
+        $crate::bits!(@op $keep, $flip, $zero, $cast, $op, $( $sel )*);
+        $crate::bits!(@ops $keep, $flip, $zero, $cast; $( $rest )*);
+    };
+    (@split $keep: ident, $flip: ident, $zero: tt, $cast: tt, $op: ident, [$( $sel: tt )*];) => {
+        $crate::bits!(@op $keep, $flip, $zero, $cast, $op, $( $sel )*);
+    };
+    (@split $keep: ident, $flip: ident, $zero: tt, $cast: tt, $op: ident, [$( $sel: tt )*]; $next: tt $( $rest: tt )*) => {
+        $crate::bits!(@split $keep, $flip, $zero, $cast, $op, [$( $sel )* $next]; $( $rest )*);
+    };
+
+    // Each operation is `(bitmap & keep) ^ flip` for its own masks,
+    // composed with the masks of the operations before it.
+    (@op $keep: ident, $flip: ident, $zero: tt, $cast: tt, set, $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!(bits, $zero, $( $sel )+);
+            $keep &= !mask;
+            $flip |= mask;
+        }
+    };
+    (@op $keep: ident, $flip: ident, $zero: tt, $cast: tt, unset, $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!(bits, $zero, $( $sel )+);
+            $keep &= !mask;
+            $flip &= !mask;
+        }
+    };
+    (@op $keep: ident, $flip: ident, $zero: tt, $cast: tt, toggle, $( $sel: tt )+) => {
+        {
+            $flip ^= $crate::__mask!(bits, $zero, $( $sel )+);
+        }
+    };
+    (@op $keep: ident, $flip: ident, $zero: tt, [$( $cast: tt )*], put, [$( $sel: tt )*] = $value: expr) => {
+        {
+            let mask = $crate::__mask!(bits, $zero, [$( $sel )*]);
+            $keep &= !mask;
+            $flip = $crate::put_bits!(@put bits, $flip, mask, ($value $( $cast )*));
+        }
+    };
+    (@op $keep: ident, $flip: ident, $zero: tt, [$( $cast: tt )*], put, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            let mask = $crate::__mask!(bits, $zero, rev [$( $sel )*]);
+            $keep &= !mask;
+            $flip = $crate::put_bits!(@put bits, $flip, mask, ($value $( $cast )*));
+        }
+    };
+}
@@ -0,0 +1,56 @@
+/// Checked version of [`is_set!`](crate::is_set).
+///
+/// Accepts the same patterns, but validates every bit position
+/// and range against the bit count of the type before checking any bits.
+/// Returns `Ok(bool)` if they are valid, otherwise a [`BitError`](crate::BitError)
+/// describing the first invalid position or range.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{checked_is_set, BitError};
+///
+/// let bitmap: u8 = 0b_0011_1100;
+///
+/// assert_eq!(checked_is_set!(bitmap, u8, [2..6]), Ok(true));
+/// assert_eq!(checked_is_set!(bitmap, rev [start = 0, count = 3]), Ok(false));
+///
+/// assert_eq!(
+///     checked_is_set!(bitmap, u8, [start = 4, count = 5]),
+///     Err(BitError::CountOverflow { start: 4, count: 5, max_bits: 8 })
+/// );
+/// assert_eq!(
+///     checked_is_set!(bitmap, 8),
+///     Err(BitError::PositionOutOfRange { pos: 8, max_bits: 8 })
+/// );
+/// ```
+#[macro_export]
+macro_rules! checked_is_set {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_is_set, (bitmap ^ bitmap), [$( $sel )*]).map(|mask| (bitmap & mask) == mask)
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_is_set, (bitmap ^ bitmap), rev $( $sel )+).map(|mask| (bitmap & mask) == mask)
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_is_set, (bitmap ^ bitmap), $bit_pos).map(|mask| (bitmap & mask) == mask)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        $crate::__checked_mask!(checked_is_set, (0 as $ty), $( $sel )+).map(|mask| (($bitmap as $ty) & mask) == mask)
+    };
+}
@@ -0,0 +1,240 @@
+/// Checked version of [`mask!`](crate::mask).
+///
+/// Accepts the same patterns but validates every bit position
+/// and range against the bit count of the type.
+/// Returns `Ok(mask)` if they are valid, otherwise a [`BitError`](crate::BitError)
+/// describing the first invalid one.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{checked_mask, BitError};
+///
+/// assert_eq!(checked_mask!([2..6], u8), Ok(0b_0011_1100));
+/// assert_eq!(checked_mask!(rev [start = 0, count = 4], u8), Ok(0b_1111_0000));
+///
+/// assert_eq!(
+///     checked_mask!([2..9], u8),
+///     Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+/// );
+/// assert_eq!(
+///     checked_mask!([6..2], u8),
+///     Err(BitError::StartAfterEnd { start: 6, end: 2 })
+/// );
+/// assert_eq!(
+///     checked_mask!(rev [1, 8], u8),
+///     Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+/// );
+/// ```
+#[macro_export]
+macro_rules! checked_mask {
+    ([$( $sel: tt )*], $ty: ty) => {
+        $crate::__checked_mask!(checked_mask, (0 as $ty), [$( $sel )*])
+    };
+    (rev [$( $sel: tt )*], $ty: ty) => {
+        $crate::__checked_mask!(checked_mask, (0 as $ty), rev [$( $sel )*])
+    };
+    (rev $bit_pos: expr, $ty: ty) => {
+        $crate::__checked_mask!(checked_mask, (0 as $ty), rev $bit_pos)
+    };
+    ($bit_pos: expr, $ty: ty) => {
+        $crate::__checked_mask!(checked_mask, (0 as $ty), $bit_pos)
+    };
+}
+
+/// Checked counterpart of `__mask!`, taking the same arguments.
+/// Evaluates to `Result<mask, BitError>`.
+///
+/// Positions are converted to `usize` before validation, saturating
+/// negative ones and ones that don't fit, so they show up as out of range.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_mask {
+    ($name: ident, $zero: tt, [..]) => {
+        $crate::__private::Ok::<_, $crate::BitError>(!$zero)
+    };
+    ($name: ident, $zero: tt, rev [..]) => {
+        $crate::__private::Ok::<_, $crate::BitError>(!$zero)
+    };
+    ($name: ident, $zero: tt, [start = $start: expr, count = $count: expr]) => {
+        $crate::__checked_mask!(@count $name, $zero, (), $start, $count)
+    };
+    ($name: ident, $zero: tt, rev [start = $start: expr, count = $count: expr]) => {
+        $crate::__checked_mask!(@count $name, $zero, (rev), $start, $count)
+    };
+    ($name: ident, $zero: tt, [$( $sel: tt )*]) => {
+        $crate::__split_list!(__checked_mask, $name, $zero, (), [] [] $( $sel )*)
+    };
+    ($name: ident, $zero: tt, rev [$( $sel: tt )*]) => {
+        $crate::__split_list!(__checked_mask, $name, $zero, (rev), [] [] $( $sel )*)
+    };
+    ($name: ident, $zero: tt, rev $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let pos = $crate::__private::Position::to_position($bit_pos);
+            match $crate::__private::check_rev_bit(pos, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__private::Ok($crate::__mask!($name, $zero, rev pos)),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    ($name: ident, $zero: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let pos = $crate::__private::Position::to_position($bit_pos);
+            match $crate::__private::check_bit(pos, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__private::Ok($crate::__mask!($name, $zero, pos)),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+
+    (@items $name: ident, $zero: tt, $mode: tt, $( [$( $item: tt )*] )+) => {
+        {
+            let mut mask = $zero;
+            let mut result = $crate::__private::Ok::<(), $crate::BitError>(());
+            $(
+                if result.is_ok() {
+                    match $crate::__checked_mask!(@item $name, $zero, $mode, [$( $item )*]) {
+                        $crate::__private::Ok(item) => mask |= item,
+                        $crate::__private::Err(err) => result = $crate::__private::Err(err),
+                    }
+                }
+            )+
+            match result {
+                $crate::__private::Ok(()) => $crate::__private::Ok(mask),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [rev $( $item: tt )+]) => {
+        $crate::__checked_mask!(@item $name, $zero, (rev), [$( $item )+])
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [start = $start: expr, count = $count: expr]) => {
+        $crate::__checked_mask!(@count $name, $zero, $mode, $start, $count)
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [start = $start: expr, count = $count: expr, step = $step: expr]) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($start);
+            #[allow(unused_parens)]
+            let count = $crate::__private::Position::to_position($count);
+            #[allow(unused_parens)]
+            let step = $crate::__private::Position::to_position($step);
+            match $crate::__private::check_stepped_count(start, count, step, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__private::Ok($crate::__mask!(@stepped $zero, $mode, start, count, step)),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [$( $item: tt )*]) => {
+        $crate::__split_range!(__checked_mask, $name, $zero, $mode, [] $( $item )*)
+    };
+
+    (@list $name: ident, $zero: tt, (), [$( $bit_pos: expr ),* $(,)?]) => {
+        {
+            let positions: &[usize] = &[$( $crate::__private::Position::to_position($bit_pos) ),*];
+            match $crate::__private::check_bits(positions, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => {
+                    let mut mask = $zero;
+                    for &pos in positions {
+                        mask |= $crate::__mask!($name, $zero, pos);
+                    }
+                    $crate::__private::Ok(mask)
+                }
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@list $name: ident, $zero: tt, (rev), [$( $bit_pos: expr ),* $(,)?]) => {
+        {
+            let positions: &[usize] = &[$( $crate::__private::Position::to_position($bit_pos) ),*];
+            match $crate::__private::check_rev_bits(positions, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => {
+                    let mut mask = $zero;
+                    for &pos in positions {
+                        mask |= $crate::__mask!($name, $zero, rev pos);
+                    }
+                    $crate::__private::Ok(mask)
+                }
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+
+    (@count $name: ident, $zero: tt, $mode: tt, $start: expr, $count: expr) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($start);
+            match $crate::__private::check_count(start, $crate::__private::Position::to_position($count), $crate::max_bits!($zero)) {
+                $crate::__private::Ok(end) => $crate::__checked_mask!(@range $name, $zero, $mode, [start], [end]),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [], []) => {
+        $crate::__private::Ok::<_, $crate::BitError>(!$zero)
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [], [$( $end: tt )+]) => {
+        $crate::__checked_mask!(@range $name, $zero, $mode, [0], [$( $end )+])
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [= $( $end: tt )+]) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($( $start )+);
+            #[allow(unused_parens)]
+            let end = $crate::__private::Position::to_position($( $end )+);
+            match $crate::__private::check_inclusive_range(start, end, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__checked_mask!(@range $name, $zero, $mode, [start], [end + 1]),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], []) => {
+        $crate::__checked_mask!(@range $name, $zero, $mode, [$( $start )+], [$crate::max_bits!($zero)])
+    };
+    (@range $name: ident, $zero: tt, ($( $rev: tt )?), [$( $start: tt )+], [$( $end: tt )+]) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($( $start )+);
+            #[allow(unused_parens)]
+            let end = $crate::__private::Position::to_position($( $end )+);
+            match $crate::__private::check_range(start, end, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__private::Ok($crate::__mask!($name, $zero, $($rev)? [start..end])),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, [], $end: tt, $step: expr) => {
+        $crate::__checked_mask!(@step $name, $zero, $mode, [0], $end, $step)
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, $start: tt, [], $step: expr) => {
+        $crate::__checked_mask!(@step $name, $zero, $mode, $start, [$crate::max_bits!($zero)], $step)
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [= $( $end: tt )+], $step: expr) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($( $start )+);
+            #[allow(unused_parens)]
+            let end = $crate::__private::Position::to_position($( $end )+);
+            match $crate::__private::check_inclusive_range(start, end, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(()) => $crate::__checked_mask!(@step $name, $zero, $mode, [start], [end + 1], $step),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [$( $end: tt )+], $step: expr) => {
+        {
+            #[allow(unused_parens)]
+            let start = $crate::__private::Position::to_position($( $start )+);
+            #[allow(unused_parens)]
+            let end = $crate::__private::Position::to_position($( $end )+);
+            #[allow(unused_parens)]
+            let step = $crate::__private::Position::to_position($step);
+            match $crate::__private::check_stepped_range(start, end, step, $crate::max_bits!($zero)) {
+                $crate::__private::Ok(count) => $crate::__private::Ok($crate::__mask!(@stepped $zero, $mode, start, count, step)),
+                $crate::__private::Err(err) => $crate::__private::Err(err),
+            }
+        }
+    };
+}
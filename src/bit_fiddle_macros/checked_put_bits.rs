@@ -0,0 +1,144 @@
+/// Checked version of [`put_bits!`](crate::put_bits).
+///
+/// Accepts the same patterns, but validates every bit position
+/// and range against the bit count of the type, and rejects values
+/// that don't fit in the field with [`BitError::ValueTooWide`](crate::BitError::ValueTooWide)
+/// instead of dropping their extra bits. Fields whose bits aren't next to
+/// each other are rejected with [`BitError::NonContiguousField`](crate::BitError::NonContiguousField).
+///
+/// With a type, `value` can be of any integer type and is rejected
+/// if it doesn't fit in the type, before being converted to it.
+/// Unsuffixed literals are `i32`s, like anywhere else.
+///
+/// Patterns using `in` return the bitmap as it was before the change
+/// in `Ok` and leave the bitmap untouched on error.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{checked_put_bits, BitError};
+///
+/// let mut reg: u16 = 0;
+///
+/// assert_eq!(checked_put_bits!(reg, u16, [4..8] = 0b_1010), Ok(0b_1010_0000));
+///
+/// assert_eq!(
+///     checked_put_bits!(in reg, u16, [4..8] = 0b_1_0000),
+///     Err(BitError::ValueTooWide { field_bits: 4 })
+/// );
+/// assert_eq!(
+///     checked_put_bits!(in reg, rev [12..17] = 0),
+///     Err(BitError::EndOutOfRange { end: 17, max_bits: 16 })
+/// );
+/// assert_eq!(reg, 0);
+///
+/// checked_put_bits!(in reg, rev [..4] = 0b_1111).unwrap();
+/// assert_eq!(reg, 0b_1111_0000_0000_0000);
+/// ```
+#[macro_export]
+macro_rules! checked_put_bits {
+    ($bitmap: tt, [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            #[allow(unused_parens)]
+            let value = $value;
+            $crate::__checked_mask!(checked_put_bits, (bitmap ^ bitmap), [$( $sel )*])
+                .and_then(|mask| $crate::checked_put_bits!(@put (bitmap ^ bitmap), bitmap, mask, $crate::__private::Some(value)))
+        }
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            #[allow(unused_parens)]
+            let value = $value;
+            $crate::__checked_mask!(checked_put_bits, (bitmap ^ bitmap), rev [$( $sel )*])
+                .and_then(|mask| $crate::checked_put_bits!(@put (bitmap ^ bitmap), bitmap, mask, $crate::__private::Some(value)))
+        }
+    };
+
+    (in $bitmap: expr, [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let value = $value;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__checked_mask!(checked_put_bits, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            mask
+                .and_then(|mask| $crate::checked_put_bits!(@put zero, *bitmap, mask, $crate::__private::Some(value)))
+                .map(|res| $crate::__private::replace(bitmap, res))
+        }
+    };
+
+    (in $bitmap: expr, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let value = $value;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__checked_mask!(checked_put_bits, zero, rev [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            mask
+                .and_then(|mask| $crate::checked_put_bits!(@put zero, *bitmap, mask, $crate::__private::Some(value)))
+                .map(|res| $crate::__private::replace(bitmap, res))
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $crate::checked_put_bits!(@value $ty, $value);
+            $crate::__checked_mask!(checked_put_bits, (0 as $ty), [$( $sel )*])
+                .and_then(|mask| $crate::checked_put_bits!(@put (0 as $ty), ($bitmap as $ty), mask, value))
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $crate::checked_put_bits!(@value $ty, $value);
+            $crate::__checked_mask!(checked_put_bits, (0 as $ty), rev [$( $sel )*])
+                .and_then(|mask| $crate::checked_put_bits!(@put (0 as $ty), ($bitmap as $ty), mask, value))
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $crate::checked_put_bits!(@value $ty, $value);
+            let mask = $crate::__checked_mask!(checked_put_bits, (0 as $ty), [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            mask
+                .and_then(|mask| $crate::checked_put_bits!(@put (0 as $ty), *bitmap, mask, value))
+                .map(|res| $crate::__private::replace(bitmap, res))
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $crate::checked_put_bits!(@value $ty, $value);
+            let mask = $crate::__checked_mask!(checked_put_bits, (0 as $ty), rev [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            mask
+                .and_then(|mask| $crate::checked_put_bits!(@put (0 as $ty), *bitmap, mask, value))
+                .map(|res| $crate::__private::replace(bitmap, res))
+        }
+    };
+
+    // `$value` is `None` if it doesn't fit in the type of the bitmap.
+    (@put $zero: tt, $bitmap: expr, $mask: expr, $value: expr) => {
+        match ($crate::__private::check_contiguous($mask as u128), $value) {
+            ($crate::__private::Err(err), _) => $crate::__private::Err(err),
+            ($crate::__private::Ok(()), $crate::__private::Some(value))
+                if $crate::put_bits!(@fits $zero, $mask, value) =>
+            {
+                $crate::__private::Ok($crate::put_bits!(@put checked_put_bits, $bitmap, $mask, value))
+            }
+            ($crate::__private::Ok(()), _) => $crate::__private::Err($crate::BitError::ValueTooWide {
+                field_bits: $mask.count_ones() as usize,
+            }),
+        }
+    };
+    // Widens the value first, so it is only converted if it fits.
+    (@value $ty: ty, $value: expr) => {
+        $crate::__private::fit::<$ty, _>($value)
+    };
+}
@@ -0,0 +1,93 @@
+/// Checked version of [`unset!`](crate::unset).
+///
+/// Accepts the same patterns, but validates every bit position
+/// and range against the bit count of the type before unsetting any bits.
+/// Instead of the bitmap, it returns a `Result` with a
+/// [`BitError`](crate::BitError) describing the first invalid position or range.
+///
+/// Patterns using `in` return the bitmap as it was before the change
+/// in `Ok` and leave the bitmap untouched on error.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{checked_unset, BitError};
+///
+/// let mut bitmap: u8 = 0b_1111_1111;
+///
+/// assert_eq!(checked_unset!(bitmap, u8, [2..4]), Ok(0b_1111_0011));
+///
+/// assert_eq!(
+///     checked_unset!(in bitmap, u8, rev 9),
+///     Err(BitError::RevUnderflow { pos: 9, max_bits: 8 })
+/// );
+/// assert_eq!(bitmap, 0b_1111_1111);
+///
+/// checked_unset!(in bitmap, u8, [1, 7]).unwrap();
+/// assert_eq!(bitmap, 0b_0111_1101);
+/// ```
+#[macro_export]
+macro_rules! checked_unset {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_unset, (bitmap ^ bitmap), [$( $sel )*]).map(|mask| bitmap & !mask)
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_unset, (bitmap ^ bitmap), rev $( $sel )+).map(|mask| bitmap & !mask)
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__checked_mask!(checked_unset, (bitmap ^ bitmap), $bit_pos).map(|mask| bitmap & !mask)
+        }
+    };
+
+    (in $bitmap: expr, [$( $sel: tt )*]) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__checked_mask!(checked_unset, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            mask.map(|mask| $crate::__private::replace(bitmap, *bitmap & !mask))
+        }
+    };
+
+    (in $bitmap: expr, rev $( $sel: tt )+) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__checked_mask!(checked_unset, zero, rev $( $sel )+);
+            let bitmap = &mut $bitmap;
+            mask.map(|mask| $crate::__private::replace(bitmap, *bitmap & !mask))
+        }
+    };
+
+    (in $bitmap: expr, $bit_pos: expr) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__checked_mask!(checked_unset, zero, $bit_pos);
+            let bitmap = &mut $bitmap;
+            mask.map(|mask| $crate::__private::replace(bitmap, *bitmap & !mask))
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        $crate::__checked_mask!(checked_unset, (0 as $ty), $( $sel )+).map(|mask| ($bitmap as $ty) & !mask)
+    };
+
+    (in $bitmap: expr, $ty: ty, $( $sel: tt )+) => {
+        {
+            let mask = $crate::__checked_mask!(checked_unset, (0 as $ty), $( $sel )+);
+            let bitmap: &mut $ty = &mut $bitmap;
+            mask.map(|mask| $crate::__private::replace(bitmap, *bitmap & !mask))
+        }
+    };
+}
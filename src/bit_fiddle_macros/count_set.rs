@@ -0,0 +1,55 @@
+/// Macro for counting the set bits among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the count as `u32`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its set bits are counted.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::count_set;
+///
+/// let bitmap: u64 = 0b_1011_0000_0110;
+///
+/// assert_eq!(count_set!(bitmap), 5);
+/// assert_eq!(count_set!(bitmap, u64, [8..16]), 3);
+/// assert_eq!(count_set!(bitmap, [1, 5, 9]), 2);
+/// assert_eq!(count_set!(bitmap, rev [0..4]), 0);
+/// assert_eq!(count_set!(0b_0111_0000, u8, rev [..4]), 3);
+/// ```
+#[macro_export]
+macro_rules! count_set {
+    ($bitmap: tt) => {
+        $bitmap.count_ones()
+    };
+
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(count_set, (bitmap ^ bitmap), [$( $sel )*])).count_ones()
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(count_set, (bitmap ^ bitmap), rev $( $sel )+)).count_ones()
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!(count_set, (bitmap ^ bitmap), $bit_pos)).count_ones()
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        (($bitmap as $ty) & $crate::__mask!(count_set, (0 as $ty), $( $sel )+)).count_ones()
+    };
+}
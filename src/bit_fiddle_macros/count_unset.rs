@@ -0,0 +1,55 @@
+/// Macro for counting the unset bits among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the count as `u32`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its unset bits are counted.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::count_unset;
+///
+/// let bitmap: u16 = 0b_1011_0000_0110;
+///
+/// assert_eq!(count_unset!(bitmap), 11);
+/// assert_eq!(count_unset!(bitmap, u16, [8..16]), 5);
+/// assert_eq!(count_unset!(bitmap, [1, 5, 9]), 1);
+/// assert_eq!(count_unset!(bitmap, rev [0..4]), 4);
+/// assert_eq!(count_unset!(0b_0111_0000, u8, rev [..4]), 1);
+/// ```
+#[macro_export]
+macro_rules! count_unset {
+    ($bitmap: tt) => {
+        $bitmap.count_zeros()
+    };
+
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (!bitmap & $crate::__mask!(count_unset, (bitmap ^ bitmap), [$( $sel )*])).count_ones()
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (!bitmap & $crate::__mask!(count_unset, (bitmap ^ bitmap), rev $( $sel )+)).count_ones()
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (!bitmap & $crate::__mask!(count_unset, (bitmap ^ bitmap), $bit_pos)).count_ones()
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        (!($bitmap as $ty) & $crate::__mask!(count_unset, (0 as $ty), $( $sel )+)).count_ones()
+    };
+}
@@ -0,0 +1,25 @@
+/// Panics with the error of `$check` when it fails, naming the macro `$name`.
+///
+/// Only does something with the `debug-checks` feature and, like `debug_assert!`,
+/// only when debug assertions are enabled in the crate using the macros.
+#[cfg(feature = "debug-checks")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_check {
+    ($name: ident, $check: expr) => {
+        if cfg!(debug_assertions) {
+            if let $crate::__private::Err(err) = $check {
+                $crate::__private::debug_check_failed(concat!(stringify!($name), "!"), err);
+            }
+        }
+    };
+}
+
+/// Without the `debug-checks` feature, expands to nothing
+/// so the expansions cost the same as without any checks.
+#[cfg(not(feature = "debug-checks"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_check {
+    ($name: ident, $check: expr) => {};
+}
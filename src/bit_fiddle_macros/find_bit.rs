@@ -0,0 +1,142 @@
+/// Shared implementation of `first_set!`, `last_set!`, `first_unset!`,
+/// `last_unset!` and `nth_set!`.
+///
+/// `$name` is the macro being expanded, `$find` is `first` or `last`
+/// and `$which` is `set` or `unset`. The rest are the arguments
+/// passed to the macro.
+///
+/// Positions of `rev` selectors are returned counted from the left,
+/// so `first` is the set bit with the lowest `rev` position.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __find_bit {
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__find_bit!(@$find (bitmap ^ bitmap), (), $crate::__find_bit!(@$which bitmap))
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!($name, (bitmap ^ bitmap), [$( $sel )*]);
+            $crate::__find_bit!(@$find (bitmap ^ bitmap), (), $crate::__find_bit!(@$which bitmap) & mask)
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!($name, (bitmap ^ bitmap), rev $( $sel )+);
+            $crate::__find_bit!(@$find (bitmap ^ bitmap), (rev), $crate::__find_bit!(@$which bitmap) & mask)
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!($name, (bitmap ^ bitmap), $bit_pos);
+            $crate::__find_bit!(@$find (bitmap ^ bitmap), (), $crate::__find_bit!(@$which bitmap) & mask)
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, $ty: ty, [$( $sel: tt )*]) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), [$( $sel )*]);
+            $crate::__find_bit!(@$find (0 as $ty), (), $crate::__find_bit!(@$which ($bitmap as $ty)) & mask)
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, $ty: ty, rev $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), rev $( $sel )+);
+            $crate::__find_bit!(@$find (0 as $ty), (rev), $crate::__find_bit!(@$which ($bitmap as $ty)) & mask)
+        }
+    };
+
+    ($name: ident, $find: ident, $which: ident, $bitmap: tt, $ty: ty, $bit_pos: expr) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), $bit_pos);
+            $crate::__find_bit!(@$find (0 as $ty), (), $crate::__find_bit!(@$which ($bitmap as $ty)) & mask)
+        }
+    };
+
+    (@set $bitmap: expr) => {
+        $bitmap
+    };
+    (@unset $bitmap: expr) => {
+        !$bitmap
+    };
+
+    // Lowest position of the bits in `$bits`.
+    (@first $zero: tt, (), $bits: expr) => {
+        {
+            let bits = $bits;
+            if bits == $zero {
+                $crate::__private::None
+            } else {
+                $crate::__private::Some(bits.trailing_zeros())
+            }
+        }
+    };
+    (@first $zero: tt, (rev), $bits: expr) => {
+        {
+            let bits = $bits;
+            if bits == $zero {
+                $crate::__private::None
+            } else {
+                $crate::__private::Some(bits.leading_zeros())
+            }
+        }
+    };
+    // Highest position of the bits in `$bits`.
+    (@last $zero: tt, (), $bits: expr) => {
+        {
+            let bits = $bits;
+            if bits == $zero {
+                $crate::__private::None
+            } else {
+                $crate::__private::Some($crate::max_bits!($zero) as u32 - 1 - bits.leading_zeros())
+            }
+        }
+    };
+    (@last $zero: tt, (rev), $bits: expr) => {
+        {
+            let bits = $bits;
+            if bits == $zero {
+                $crate::__private::None
+            } else {
+                $crate::__private::Some($crate::max_bits!($zero) as u32 - 1 - bits.trailing_zeros())
+            }
+        }
+    };
+    // Position of the `$n`th (from 0) of the bits in `$bits`,
+    // found by clearing the first bit `$n` times.
+    (@nth $zero: tt, (), $bits: expr, $n: expr) => {
+        {
+            let mut bits = $bits;
+            let mut n = $n as u32;
+            while n > 0 && bits != $zero {
+                bits &= bits.wrapping_sub(1);
+                n -= 1;
+            }
+            $crate::__find_bit!(@first $zero, (), bits)
+        }
+    };
+    (@nth $zero: tt, (rev), $bits: expr, $n: expr) => {
+        {
+            let mut bits = $bits;
+            let mut n = $n as u32;
+            while n > 0 && bits != $zero {
+                bits &= !(($zero | 1) << ($crate::max_bits!($zero) as u32 - 1 - bits.leading_zeros()));
+                n -= 1;
+            }
+            $crate::__find_bit!(@first $zero, (rev), bits)
+        }
+    };
+}
@@ -0,0 +1,30 @@
+/// Macro for finding the first set bit among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the position as `Option<u32>`, `None` if no bit is set.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its bits are searched.
+/// With `rev` selectors the position is counted from the left, like the
+/// `rev` positions themselves, so the first bit is the one with the lowest
+/// `rev` position.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::first_set;
+///
+/// let bitmap: u16 = 0b_0110_1000_0100;
+///
+/// assert_eq!(first_set!(bitmap), Some(2));
+/// assert_eq!(first_set!(bitmap, u16, [4..12]), Some(7));
+/// assert_eq!(first_set!(bitmap, [0, 1, 3..7]), None);
+/// assert_eq!(first_set!(bitmap, rev [..]), Some(5));
+/// assert_eq!(first_set!(0b_0010_0100, u8, rev [3..]), Some(5));
+/// ```
+#[macro_export]
+macro_rules! first_set {
+    ($( $args: tt )+) => {
+        $crate::__find_bit!(first_set, first, set, $( $args )+)
+    };
+}
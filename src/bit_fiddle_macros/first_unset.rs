@@ -0,0 +1,30 @@
+/// Macro for finding the first unset bit among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the position as `Option<u32>`, `None` if no bit is unset.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its bits are searched.
+/// With `rev` selectors the position is counted from the left, like the
+/// `rev` positions themselves, so the first bit is the one with the lowest
+/// `rev` position.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::first_unset;
+///
+/// let bitmap: u16 = 0b_1111_1001_0111_1111;
+///
+/// assert_eq!(first_unset!(bitmap), Some(7));
+/// assert_eq!(first_unset!(bitmap, u16, [8..16]), Some(9));
+/// assert_eq!(first_unset!(bitmap, [0..7, 8]), None);
+/// assert_eq!(first_unset!(bitmap, rev [..]), Some(5));
+/// assert_eq!(first_unset!(0b_1101_1011, u8, rev [3..]), Some(5));
+/// ```
+#[macro_export]
+macro_rules! first_unset {
+    ($( $args: tt )+) => {
+        $crate::__find_bit!(first_unset, first, unset, $( $args )+)
+    };
+}
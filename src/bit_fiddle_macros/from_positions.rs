@@ -0,0 +1,40 @@
+/// Macro for building a bitmap with the bits at the given positions set.
+/// Positions can come from any `IntoIterator` of integers or
+/// references to integers, like a slice, an array or a range.
+///
+/// Returns `Result<$ty, BitError>`, failing with
+/// [`BitError::PositionOutOfRange`](crate::BitError::PositionOutOfRange)
+/// or, for `rev` positions,
+/// [`BitError::RevUnderflow`](crate::BitError::RevUnderflow) on the first
+/// position that doesn't fit in the type. Negative positions are reported
+/// as `usize::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{from_positions, BitError};
+///
+/// assert_eq!(from_positions!(u8, [0, 3, 4]), Ok(0b_0001_1001));
+/// assert_eq!(from_positions!(u8, rev [0, 3, 4]), Ok(0b_1001_1000));
+///
+/// let channels: &[u32] = &[1, 2];
+/// assert_eq!(from_positions!(u16, channels), Ok(0b_0110));
+/// assert_eq!(from_positions!(i8, 4..8), Ok(-16));
+///
+/// assert_eq!(
+///     from_positions!(u8, [1, 8]),
+///     Err(BitError::PositionOutOfRange { pos: 8, max_bits: 8 })
+/// );
+/// ```
+#[macro_export]
+macro_rules! from_positions {
+    ($ty: ty, rev $positions: expr) => {
+        $crate::__private::from_positions($positions, $crate::max_bits!(type = $ty), true)
+            .map(|bits| bits as $ty)
+    };
+
+    ($ty: ty, $positions: expr) => {
+        $crate::__private::from_positions($positions, $crate::max_bits!(type = $ty), false)
+            .map(|bits| bits as $ty)
+    };
+}
@@ -0,0 +1,95 @@
+/// Macro for reading the value of a bit field.
+/// It accepts the same patterns as [`is_set!`](crate::is_set), as long as the
+/// selected bits are next to each other, like a single bit or a range without
+/// a step. Lists and stepped ranges that leave gaps are not supported.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The selected bits are shifted down, so that the lowest of them
+/// becomes bit 0 of the result. Other bits of the result are always 0,
+/// also for signed types.
+///
+/// ```
+/// # use bit_fiddler::get_bits;
+/// let reg: u32 = 0b_1010_0110_0000;
+///
+/// assert_eq!(get_bits!(reg, u32, [4..8]), 0b_0110);
+/// assert_eq!(get_bits!(reg, [8..=11]), 0b_1010);
+/// assert_eq!(get_bits!(reg, rev [start = 24, count = 4]), 0b_0110);
+/// assert_eq!(get_bits!(reg, u32, 5), 1);
+/// ```
+///
+/// The field can be converted to another type with `as` after the selector
+/// in brackets.
+///
+/// ```
+/// # use bit_fiddler::get_bits;
+/// let reg: u32 = 0x_00ab_0000;
+///
+/// let byte: u8 = get_bits!(reg, u32, [16..24] as u8);
+/// assert_eq!(byte, 0x_ab);
+///
+/// let nibble: u8 = get_bits!(reg, rev [8..12] as u8);
+/// assert_eq!(nibble, 0x_a);
+/// ```
+///
+/// Like with `is_set!`, the type can be left out when the bitmap is a variable
+/// whose type is already known.
+#[macro_export]
+macro_rules! get_bits {
+    ($bitmap: tt, [$( $sel: tt )*] as $out: ty) => {
+        ($crate::get_bits!($bitmap, [$( $sel )*]) as $out)
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*] as $out: ty) => {
+        ($crate::get_bits!($bitmap, rev [$( $sel )*]) as $out)
+    };
+
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::get_bits!(@field (bitmap ^ bitmap), bitmap, [$( $sel )*])
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::get_bits!(@field (bitmap ^ bitmap), bitmap, rev $( $sel )+)
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::get_bits!(@field (bitmap ^ bitmap), bitmap, $bit_pos)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*] as $out: ty) => {
+        ($crate::get_bits!($bitmap, $ty, [$( $sel )*]) as $out)
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*] as $out: ty) => {
+        ($crate::get_bits!($bitmap, $ty, rev [$( $sel )*]) as $out)
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        $crate::get_bits!(@field (0 as $ty), ($bitmap as $ty), $( $sel )+)
+    };
+
+    (@field $zero: tt, $bitmap: expr, $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!(get_bits, $zero, $( $sel )+);
+            $crate::__debug_check!(get_bits, $crate::__private::check_contiguous(mask as u128));
+            // Shifting a signed field down copies its sign bit,
+            // so only the bits below the field's old position are kept.
+            let shift = mask.trailing_zeros();
+            $crate::__mask!(@shr $bitmap & mask, shift)
+                & $crate::__mask!(@low $zero, $crate::max_bits!($zero) - shift as usize)
+        }
+    };
+}
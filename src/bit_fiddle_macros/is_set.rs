@@ -1,7 +1,20 @@
 /// Macro for checking if single, multiple or range of bits are set.
 /// It accepts multiple patterns for different use cases.
 /// It doesn't do any overflow or underflow checks. Behaviour on passing
-/// invalid args is undefined.
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+/// See [`checked_is_set!`](crate::checked_is_set) for a variant returning errors instead.
+///
+/// The type can be left out when the bitmap is a variable whose type
+/// is already known. The type and its bit count are then taken from
+/// the bitmap itself. Literals still need the type to be passed.
+///
+/// ```
+/// # use bit_fiddler::is_set;
+/// let bitmap: u16 = 0b_1111_0000_0000_0000;
+///
+/// assert!(is_set!(bitmap, rev [0..4]));
+/// assert!(!is_set!(bitmap, 0));
+/// ```
 ///
 /// A common thing in these patterns is `rev`.
 /// All patterns support this. Putting `rev` before the
@@ -69,101 +82,37 @@
 /// ```
 #[macro_export]
 macro_rules! is_set {
-    ($bitmap: tt, $ty: ty, [..]) => {
-        {
-            $bitmap == !0
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [..]) => {
-        {
-            $bitmap == !0
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, [$( $bit_pos: tt),*]) => {
-        {
-            let bits_to_check = ($( ((1 as $ty) << $bit_pos) | )* (0 as $ty));
-            (($bitmap as $ty) & bits_to_check) == bits_to_check
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$( $bit_pos: tt),*]) => {
-        {
-            let total_bit_count = $crate::max_bits!(type = ($ty));
-            let bits_to_check = ($( ((1 as $ty) << (total_bit_count - $bit_pos - 1)) | )* (0 as $ty));
-            (($bitmap as $ty) & bits_to_check) == bits_to_check
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, [$start_pos: tt .. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!([$start_pos..$end_pos], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, [$start_pos: tt ..]) => {
-        {
-            let mask = $crate::mask!([$start_pos..], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, [.. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!([..$end_pos], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, [start = $start_pos: tt, count = $count: tt]) => {
-        {
-            let mask = $crate::mask!([start = $start_pos, count = $count], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt .. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!(rev [$start_pos..$end_pos], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt ..]) => {
-        {
-            let mask = $crate::mask!(rev [$start_pos..], ($ty));
-            ($bitmap & mask) == mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [.. $end_pos: tt]) => {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
         {
-            let mask = $crate::mask!(rev [..$end_pos], ($ty));
-            ($bitmap & mask) == mask
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!(is_set, (bitmap ^ bitmap), [$( $sel )*]);
+            (bitmap & mask) == mask
         }
     };
 
-    ($bitmap: tt, $ty: ty, rev [start = $start_pos: tt, count = $count: tt]) => {
+    ($bitmap: tt, rev $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!(rev [start = $start_pos, count = $count], ($ty));
-            ($bitmap & mask) == mask
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!(is_set, (bitmap ^ bitmap), rev $( $sel )+);
+            (bitmap & mask) == mask
         }
     };
 
-    ($bitmap: tt, $ty: ty, rev $bit_pos: tt) => {
+    ($bitmap: tt, $bit_pos: expr) => {
         {
-            let total_bit_count = $crate::max_bits!(type = ($ty));
-            ( ($bitmap as $ty) & ((1 as $ty) << (total_bit_count - $bit_pos - 1)) )
-                != (0 as $ty)
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!(is_set, (bitmap ^ bitmap), $bit_pos);
+            (bitmap & mask) == mask
         }
     };
 
-    ($bitmap: tt, $ty: ty, $bit_pos: tt) => {
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
         {
-            ( ($bitmap as $ty) & ((1 as $ty) << $bit_pos) )
-                != (0 as $ty)
+            let mask = $crate::__mask!(is_set, (0 as $ty), $( $sel )+);
+            (($bitmap as $ty) & mask) == mask
         }
     };
 }
@@ -0,0 +1,30 @@
+/// Macro for finding the last set bit among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the position as `Option<u32>`, `None` if no bit is set.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its bits are searched.
+/// With `rev` selectors the position is counted from the left, like the
+/// `rev` positions themselves, so the last bit is the one with the highest
+/// `rev` position.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::last_set;
+///
+/// let bitmap: u16 = 0b_0110_1000_0100;
+///
+/// assert_eq!(last_set!(bitmap), Some(10));
+/// assert_eq!(last_set!(bitmap, u16, [..9]), Some(7));
+/// assert_eq!(last_set!(bitmap, [0, 1, 3..7]), None);
+/// assert_eq!(last_set!(bitmap, rev [..]), Some(13));
+/// assert_eq!(last_set!(0b_0010_0100, u8, rev [..4]), Some(2));
+/// ```
+#[macro_export]
+macro_rules! last_set {
+    ($( $args: tt )+) => {
+        $crate::__find_bit!(last_set, last, set, $( $args )+)
+    };
+}
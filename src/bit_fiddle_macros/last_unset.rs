@@ -0,0 +1,30 @@
+/// Macro for finding the last unset bit among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set)
+/// and returns the position as `Option<u32>`, `None` if no bit is unset.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With only the bitmap, all of its bits are searched.
+/// With `rev` selectors the position is counted from the left, like the
+/// `rev` positions themselves, so the last bit is the one with the highest
+/// `rev` position.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::last_unset;
+///
+/// let bitmap: u16 = 0b_1111_1001_0111_1111;
+///
+/// assert_eq!(last_unset!(bitmap), Some(10));
+/// assert_eq!(last_unset!(bitmap, u16, [..10]), Some(9));
+/// assert_eq!(last_unset!(bitmap, [0..7, 8]), None);
+/// assert_eq!(last_unset!(bitmap, rev [..]), Some(8));
+/// assert_eq!(last_unset!(0b_1101_1011, u8, rev [..5]), Some(2));
+/// ```
+#[macro_export]
+macro_rules! last_unset {
+    ($( $args: tt )+) => {
+        $crate::__find_bit!(last_unset, last, unset, $( $args )+)
+    };
+}
@@ -1,5 +1,14 @@
 /// Macro for getting a bit mask over the given range.
 ///
+/// Besides ranges, it accepts every other bit pattern of [`set!`](crate::set),
+/// i.e. single bits and lists of bits, with or without `rev`.
+///
+/// Works with signed types too, where ranges never pick up
+/// the sign bit unless they include it.
+///
+/// Empty ranges, like `[3..3]`, `[..0]` or `rev [8..]` for `u8`, give an
+/// empty mask, so the other macros leave the bitmap unchanged for them.
+///
 /// # Example
 ///
 /// ```
@@ -23,61 +32,418 @@
 /// let bitmap: u8 = 0b_1111_1111;
 /// let masked_bitmap = bitmap & mask!([start = 3, count = 4], u8);
 /// assert_eq!(masked_bitmap, 0b_0111_1000);
+///
+/// assert_eq!(mask!([1, 3], u8), 0b_0000_1010);
+/// assert_eq!(mask!(rev 0, u8), 0b_1000_0000);
+/// assert_eq!(mask!([0, 4..6, rev 0], u8), 0b_1011_0001);
+/// assert_eq!(mask!([..4], i8), 0b_0000_1111);
+/// assert_eq!(mask!([8..], u8), 0);
+/// assert_eq!(mask!([0..32; step = 2], u32), 0x_5555_5555);
+/// assert_eq!(mask!([4..=7], u8), 0b_1111_0000);
 /// ```
 #[macro_export]
 macro_rules! mask {
-    ([..$end: tt], $ty: ty) => {
+    ([$( $sel: tt )*], $ty: ty) => {
+        $crate::__mask!(mask, (0 as $ty), [$( $sel )*])
+    };
+    (rev [$( $sel: tt )*], $ty: ty) => {
+        $crate::__mask!(mask, (0 as $ty), rev [$( $sel )*])
+    };
+    (rev $bit_pos: expr, $ty: ty) => {
+        $crate::__mask!(mask, (0 as $ty), rev $bit_pos)
+    };
+    ($bit_pos: expr, $ty: ty) => {
+        $crate::__mask!(mask, (0 as $ty), $bit_pos)
+    };
+}
+
+/// Builds the mask for any bit pattern accepted by the macros.
+///
+/// Instead of a type, it takes a zero of the bitmap's type (`$zero`).
+/// This is either `(0 as $ty)` when the type was given explicitly,
+/// or derived from the bitmap itself when it wasn't.
+///
+/// `$name` is the macro being expanded, used in `debug-checks` messages.
+///
+/// Every position and range bound is bound to a local first,
+/// so each expression is evaluated exactly once. The bindings allow
+/// `unused_parens`, as callers used to have to parenthesize expressions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mask {
+    ($name: ident, $zero: tt, [..]) => {
+        !$zero
+    };
+    ($name: ident, $zero: tt, rev [..]) => {
+        !$zero
+    };
+    ($name: ident, $zero: tt, [start = $start: expr, count = $count: expr]) => {
+        $crate::__mask!(@count $name, $zero, (), $start, $count)
+    };
+    ($name: ident, $zero: tt, rev [start = $start: expr, count = $count: expr]) => {
+        $crate::__mask!(@count $name, $zero, (rev), $start, $count)
+    };
+    ($name: ident, $zero: tt, [$( $bit_pos: literal ),+ $(,)?]) => {
+        $crate::__mask!(@list $name, $zero, (), [$( $bit_pos ),+])
+    };
+    ($name: ident, $zero: tt, rev [$( $bit_pos: literal ),+ $(,)?]) => {
+        $crate::__mask!(@list $name, $zero, (rev), [$( $bit_pos ),+])
+    };
+    ($name: ident, $zero: tt, [$( $sel: tt )*]) => {
+        $crate::__split_list!(__mask, $name, $zero, (), [] [] $( $sel )*)
+    };
+    ($name: ident, $zero: tt, rev [$( $sel: tt )*]) => {
+        $crate::__split_list!(__mask, $name, $zero, (rev), [] [] $( $sel )*)
+    };
+    ($name: ident, $zero: tt, rev $bit_pos: expr) => {
+        {
+            let max_bits = $crate::max_bits!($zero);
+            #[allow(unused_parens)]
+            let pos = $bit_pos;
+            $crate::__debug_check!($name, $crate::__private::check_rev_bit(pos as usize, max_bits));
+            ($zero | 1) << (max_bits - pos as usize - 1)
+        }
+    };
+    ($name: ident, $zero: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let pos = $bit_pos;
+            $crate::__debug_check!($name, $crate::__private::check_bit(pos as usize, $crate::max_bits!($zero)));
+            ($zero | 1) << pos
+        }
+    };
+
+    (@items $name: ident, $zero: tt, $mode: tt, $( [$( $item: tt )*] )+) => {
+        $( $crate::__mask!(@item $name, $zero, $mode, [$( $item )*]) | )+ $zero
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [rev $( $item: tt )+]) => {
+        $crate::__mask!(@item $name, $zero, (rev), [$( $item )+])
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [start = $start: expr, count = $count: expr]) => {
+        $crate::__mask!(@count $name, $zero, $mode, $start, $count)
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [start = $start: expr, count = $count: expr, step = $step: expr]) => {
+        {
+            #[allow(unused_parens)]
+            let start = $start as usize;
+            #[allow(unused_parens)]
+            let count = $count as usize;
+            #[allow(unused_parens)]
+            let step = $step as usize;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_stepped_count(start, count, step, $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@stepped $zero, $mode, start, count, step)
+        }
+    };
+    (@item $name: ident, $zero: tt, $mode: tt, [$( $item: tt )*]) => {
+        $crate::__split_range!(__mask, $name, $zero, $mode, [] $( $item )*)
+    };
+
+    (@list $name: ident, $zero: tt, (), [$( $bit_pos: expr ),* $(,)?]) => {
+        $( $crate::__mask!($name, $zero, $bit_pos) | )* $zero
+    };
+    (@list $name: ident, $zero: tt, (rev), [$( $bit_pos: expr ),* $(,)?]) => {
+        $( $crate::__mask!($name, $zero, rev $bit_pos) | )* $zero
+    };
+
+    (@count $name: ident, $zero: tt, $mode: tt, $start: expr, $count: expr) => {
+        {
+            #[allow(unused_parens)]
+            let start = $start;
+            #[allow(unused_parens)]
+            let count = $count;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_count(start as usize, count as usize, $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@range $name, $zero, $mode, [start], [start + count])
+        }
+    };
+
+    (@range $name: ident, $zero: tt, $mode: tt, [], []) => {
+        !$zero
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [], [= $( $end: tt )+]) => {
+        $crate::__mask!(@range $name, $zero, $mode, [0], [= $( $end )+])
+    };
+    (@range $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [= $( $end: tt )+]) => {
         {
-            let max_bits = $crate::max_bits!(type = $ty);
-            !(0 as $ty) >> (max_bits - $end)
+            #[allow(unused_parens)]
+            let start = $( $start )+;
+            #[allow(unused_parens)]
+            let end = ($( $end )+) as usize;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_inclusive_range(start as usize, end, $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@range $name, $zero, $mode, [start], [end + 1])
         }
     };
-    ([$start: tt..], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (), [], [$( $end: tt )+]) => {
         {
-            !(0 as $ty) << $start
+            let max_bits = $crate::max_bits!($zero);
+            #[allow(unused_parens)]
+            let end = $( $end )+;
+            $crate::__debug_check!($name, $crate::__private::check_range(0, end as usize, max_bits));
+            $crate::__mask!(@low $zero, end)
         }
     };
-    ([..], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (), [$( $start: tt )+], []) => {
         {
-            !(0 as $ty)
+            #[allow(unused_parens)]
+            let start = $( $start )+;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_range(start as usize, $crate::max_bits!($zero), $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@shl !$zero, start)
         }
     };
-    ([$start: tt..$end: tt], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (), [$( $start: tt )+], [$( $end: tt )+]) => {
         {
-            let max_bits = $crate::max_bits!(type = $ty);
-            (!(0 as $ty) << $start) & (!(0 as $ty) >> (max_bits - $end))
+            let max_bits = $crate::max_bits!($zero);
+            #[allow(unused_parens)]
+            let start = $( $start )+;
+            #[allow(unused_parens)]
+            let end = $( $end )+;
+            $crate::__debug_check!($name, $crate::__private::check_range(start as usize, end as usize, max_bits));
+            $crate::__mask!(@shl !$zero, start) & $crate::__mask!(@low $zero, end)
         }
     };
-    (rev [$start: tt..], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (rev), [], [$( $end: tt )+]) => {
         {
-            !(0 as $ty) >> $start
+            let max_bits = $crate::max_bits!($zero);
+            #[allow(unused_parens)]
+            let end = $( $end )+;
+            $crate::__debug_check!($name, $crate::__private::check_range(0, end as usize, max_bits));
+            $crate::__mask!(@shl !$zero, max_bits - end as usize)
         }
     };
-    (rev [..$end: tt], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (rev), [$( $start: tt )+], []) => {
         {
-            let max_bits = $crate::max_bits!(type = $ty);
-            !(0 as $ty) << (max_bits - $end)
+            #[allow(unused_parens)]
+            let start = $( $start )+;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_range(start as usize, $crate::max_bits!($zero), $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@low $zero, $crate::max_bits!($zero) - start as usize)
         }
     };
-    (rev [..], $ty: ty) => {
+    (@range $name: ident, $zero: tt, (rev), [$( $start: tt )+], [$( $end: tt )+]) => {
         {
-            !(0 as $ty)
+            let max_bits = $crate::max_bits!($zero);
+            #[allow(unused_parens)]
+            let start = $( $start )+;
+            #[allow(unused_parens)]
+            let end = $( $end )+;
+            $crate::__debug_check!($name, $crate::__private::check_range(start as usize, end as usize, max_bits));
+            $crate::__mask!(@shl !$zero, max_bits - end as usize) & $crate::__mask!(@low $zero, max_bits - start as usize)
         }
     };
-    (rev [$start: tt..$end: tt], $ty: ty) => {
+
+    (@step $name: ident, $zero: tt, $mode: tt, [], $end: tt, $step: expr) => {
+        $crate::__mask!(@step $name, $zero, $mode, [0], $end, $step)
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, $start: tt, [], $step: expr) => {
+        $crate::__mask!(@step $name, $zero, $mode, $start, [$crate::max_bits!($zero)], $step)
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [= $( $end: tt )+], $step: expr) => {
+        {
+            #[allow(unused_parens)]
+            let start = ($( $start )+) as usize;
+            #[allow(unused_parens)]
+            let end = ($( $end )+) as usize;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_inclusive_range(start, end, $crate::max_bits!($zero))
+            );
+            $crate::__mask!(@step $name, $zero, $mode, [start], [end + 1], $step)
+        }
+    };
+    (@step $name: ident, $zero: tt, $mode: tt, [$( $start: tt )+], [$( $end: tt )+], $step: expr) => {
         {
-            let max_bits = $crate::max_bits!(type = $ty);
-            (!(0 as $ty) << (max_bits - $end)) & (!(0 as $ty) >> $start)
+            #[allow(unused_parens)]
+            let start = ($( $start )+) as usize;
+            #[allow(unused_parens)]
+            let end = ($( $end )+) as usize;
+            #[allow(unused_parens)]
+            let step = $step as usize;
+            $crate::__debug_check!(
+                $name,
+                $crate::__private::check_stepped_range(start, end, step, $crate::max_bits!($zero))
+            );
+            let count = $crate::__private::stepped_count(start, end, step);
+            $crate::__mask!(@stepped $zero, $mode, start, count, step)
         }
     };
-    ([start = $start: tt, count = $count: tt], $ty: ty) => {
+    // `$count` bits, `$step` apart, starting at `$start`.
+    // A plain loop, which is folded into a constant when the arguments are constants.
+    (@stepped $zero: tt, (), $start: expr, $count: expr, $step: expr) => {
         {
-            $crate::mask!([$start..($start + $count)], ($ty))
+            let mut mask = $zero;
+            let mut i = 0;
+            while i < $count {
+                mask |= ($zero | 1) << ($start + i * $step);
+                i += 1;
+            }
+            mask
         }
     };
-    (rev [start = $start: tt, count = $count: tt], $ty: ty) => {
+    (@stepped $zero: tt, (rev), $start: expr, $count: expr, $step: expr) => {
         {
-            $crate::mask!(rev [$start..($start + $count)], ($ty))
+            let mut mask = $zero;
+            let mut i = 0;
+            while i < $count {
+                mask |= ($zero | 1) << ($crate::max_bits!($zero) - 1 - ($start + i * $step));
+                i += 1;
+            }
+            mask
         }
     };
+
+    // Mask of the lowest `$bits` bits, for `$bits` in `0..=max_bits`.
+    // Only shifts left, since right shifts of signed types would copy the sign bit.
+    (@low $zero: tt, $bits: expr) => {
+        !$crate::__mask!(@shl !$zero, $bits)
+    };
+    // `$value << $bits` for `$bits` in `0..=max_bits`, shifting everything out
+    // when `$bits` is the full width instead of overflowing.
+    // Both halves are less than the bit count of any type.
+    (@shl $value: expr, $bits: expr) => {
+        {
+            let bits = $bits;
+            ($value << (bits / 2)) << (bits - bits / 2)
+        }
+    };
+    // `$value >> $bits`, the same way as `@shl`.
+    (@shr $value: expr, $bits: expr) => {
+        {
+            let bits = $bits;
+            ($value >> (bits / 2)) >> (bits - bits / 2)
+        }
+    };
+}
+
+/// Splits the tokens of a bracketed pattern at a top level `..`.
+///
+/// Bit positions can be any expression, and `macro_rules!` doesn't allow
+/// `..` right after an `expr` fragment, so the tokens on either side
+/// are collected one at a time instead.
+///
+/// Calls back `$crate::$callback!(@range ..)` with the tokens before and
+/// after the `..` (either may be empty), `$crate::$callback!(@step ..)`
+/// with the step as well if the range ends with `; step = ..`,
+/// or `$crate::$callback!(@list ..)` if there is no `..`.
+/// The end of a `..=` range is passed as `[= end]`.
+/// `$mode` is `()` or `(rev)`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __split_range {
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $start: tt )*] .. $( $rest: tt )*) => {
+        $crate::__split_range!(@end $callback, $name, $zero, $mode, (), [$( $start )*] [] $( $rest )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $start: tt )*] ..= $( $rest: tt )*) => {
+        $crate::__split_range!(@end $callback, $name, $zero, $mode, (=), [$( $start )*] [] $( $rest )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $start: tt )*] $next: tt $( $rest: tt )*) => {
+        $crate::__split_range!($callback, $name, $zero, $mode, [$( $start )* $next] $( $rest )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $bit_pos: tt )*]) => {
+        $crate::$callback!(@list $name, $zero, $mode, [$( $bit_pos )*])
+    };
+
+    // `$inclusive` is `(=)` after `..=`, whose end is marked with a leading `=`.
+    (@end $callback: ident, $name: ident, $zero: tt, $mode: tt, (), $start: tt [$( $end: tt )*] ; step = $step: expr) => {
+        $crate::$callback!(@step $name, $zero, $mode, $start, [$( $end )*], $step)
+    };
+    (@end $callback: ident, $name: ident, $zero: tt, $mode: tt, (=), $start: tt [$( $end: tt )+] ; step = $step: expr) => {
+        $crate::$callback!(@step $name, $zero, $mode, $start, [= $( $end )+], $step)
+    };
+    (@end $callback: ident, $name: ident, $zero: tt, $mode: tt, $inclusive: tt, $start: tt [$( $end: tt )*] $next: tt $( $rest: tt )*) => {
+        $crate::__split_range!(@end $callback, $name, $zero, $mode, $inclusive, $start [$( $end )* $next] $( $rest )*)
+    };
+    (@end $callback: ident, $name: ident, $zero: tt, $mode: tt, (), $start: tt [$( $end: tt )*]) => {
+        $crate::$callback!(@range $name, $zero, $mode, $start, [$( $end )*])
+    };
+    (@end $callback: ident, $name: ident, $zero: tt, $mode: tt, (=), $start: tt [$( $end: tt )+]) => {
+        $crate::$callback!(@range $name, $zero, $mode, $start, [= $( $end )+])
+    };
+}
+
+/// Splits the tokens of a bracketed pattern into its comma separated items.
+///
+/// A `start = a, count = b` pair (optionally after `rev` and followed by
+/// `step = c`) is kept together as a single item.
+///
+/// Calls back `$crate::$callback!(@item ..)` with the tokens of the only
+/// item, or `$crate::$callback!(@items ..)` with each item in brackets
+/// if there is more than one.
+/// `$mode` is `()` or `(rev)` and applies to every item without its own `rev`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __split_list {
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $items: tt )*] []) => {
+        $crate::__split_list!(@done $callback, $name, $zero, $mode, $( $items )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $items: tt )*] [$( $item: tt )+]) => {
+        $crate::__split_list!(@done $callback, $name, $zero, $mode, $( $items )* [$( $item )+])
+    };
+    (
+        $callback: ident, $name: ident, $zero: tt, $mode: tt,
+        [$( $items: tt )*] [start = $( $start: tt )+] , count = $( $rest: tt )*
+    ) => {
+        $crate::__split_list!(
+            $callback, $name, $zero, $mode,
+            [$( $items )*] [start = $( $start )+ , count =] $( $rest )*
+        )
+    };
+    (
+        $callback: ident, $name: ident, $zero: tt, $mode: tt,
+        [$( $items: tt )*] [start = $( $start: tt )+] , step = $( $rest: tt )*
+    ) => {
+        $crate::__split_list!(
+            $callback, $name, $zero, $mode,
+            [$( $items )*] [start = $( $start )+ , step =] $( $rest )*
+        )
+    };
+    (
+        $callback: ident, $name: ident, $zero: tt, $mode: tt,
+        [$( $items: tt )*] [rev start = $( $start: tt )+] , count = $( $rest: tt )*
+    ) => {
+        $crate::__split_list!(
+            $callback, $name, $zero, $mode,
+            [$( $items )*] [rev start = $( $start )+ , count =] $( $rest )*
+        )
+    };
+    (
+        $callback: ident, $name: ident, $zero: tt, $mode: tt,
+        [$( $items: tt )*] [rev start = $( $start: tt )+] , step = $( $rest: tt )*
+    ) => {
+        $crate::__split_list!(
+            $callback, $name, $zero, $mode,
+            [$( $items )*] [rev start = $( $start )+ , step =] $( $rest )*
+        )
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $items: tt )*] [] $bit_pos: tt , $( $rest: tt )*) => {
+        $crate::__split_list!($callback, $name, $zero, $mode, [$( $items )* [$bit_pos]] [] $( $rest )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $items: tt )*] [$( $item: tt )*] , $( $rest: tt )*) => {
+        $crate::__split_list!($callback, $name, $zero, $mode, [$( $items )* [$( $item )*]] [] $( $rest )*)
+    };
+    ($callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $items: tt )*] [$( $item: tt )*] $next: tt $( $rest: tt )*) => {
+        $crate::__split_list!($callback, $name, $zero, $mode, [$( $items )*] [$( $item )* $next] $( $rest )*)
+    };
+
+    (@done $callback: ident, $name: ident, $zero: tt, $mode: tt,) => {
+        $crate::$callback!(@item $name, $zero, $mode, [])
+    };
+    (@done $callback: ident, $name: ident, $zero: tt, $mode: tt, [$( $item: tt )*]) => {
+        $crate::$callback!(@item $name, $zero, $mode, [$( $item )*])
+    };
+    (@done $callback: ident, $name: ident, $zero: tt, $mode: tt, $( $items: tt )+) => {
+        $crate::$callback!(@items $name, $zero, $mode, $( $items )+)
+    };
 }
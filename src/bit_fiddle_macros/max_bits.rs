@@ -1,5 +1,6 @@
 /// For getting total bit count
 /// by type or identifier.
+/// Can be used in const contexts.
 ///
 /// # Example
 ///
@@ -16,9 +17,9 @@
 #[macro_export]
 macro_rules! max_bits {
     ($bitmap: tt) => {
-        std::mem::size_of_val(&$bitmap) * 8
+        $crate::__private::bits_of(&$bitmap)
     };
     (type = $ty: ty) => {
-        std::mem::size_of::<$ty>() * 8
+        ::core::mem::size_of::<$ty>() * 8
     };
 }
@@ -0,0 +1,54 @@
+/// Macro for finding which of single, multiple or range of bits are not set.
+/// It accepts the same patterns as [`is_set!`](crate::is_set) and returns
+/// the mask of the selected bits that are unset, which is zero exactly
+/// when `is_set!` with the same args is `true`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The positions of the missing bits can be listed with
+/// [`ones!`](crate::ones), e.g. to report a failed check.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::{missing_bits, ones};
+///
+/// let status: u32 = 0b_1101_1111_0111;
+///
+/// let missing = missing_bits!(status, u32, [0, 3, 7..12]);
+/// assert_eq!(missing, 0b_0010_0000_1000);
+/// assert!(ones!(missing).eq([3, 9]));
+///
+/// assert_eq!(missing_bits!(status, [0..3]), 0);
+/// assert_eq!(missing_bits!(status, rev [0, 1]), 0xc000_0000);
+/// ```
+#[macro_export]
+macro_rules! missing_bits {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            !bitmap & $crate::__mask!(missing_bits, (bitmap ^ bitmap), [$( $sel )*])
+        }
+    };
+
+    ($bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            !bitmap & $crate::__mask!(missing_bits, (bitmap ^ bitmap), rev $( $sel )+)
+        }
+    };
+
+    ($bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            !bitmap & $crate::__mask!(missing_bits, (bitmap ^ bitmap), $bit_pos)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        !($bitmap as $ty) & $crate::__mask!(missing_bits, (0 as $ty), $( $sel )+)
+    };
+}
@@ -1,6 +1,38 @@
+mod all_unset;
+mod any_set;
+mod assign;
+mod bit_positions;
+mod bits;
+mod checked_is_set;
+mod checked_mask;
+mod checked_put_bits;
+mod checked_set;
+mod checked_toggle;
+mod checked_unset;
+mod count_set;
+mod count_unset;
+mod debug_check;
+mod find_bit;
+mod first_set;
+mod first_unset;
+mod from_positions;
+mod get_bits;
 mod is_set;
+mod last_set;
+mod last_unset;
 mod mask;
 mod max_bits;
+mod missing_bits;
+mod none_set;
+mod nth_set;
+mod ones;
+mod put_bits;
 mod set;
+mod test_and;
+mod test_and_set;
+mod test_and_toggle;
+mod test_and_unset;
+mod to_positions;
 mod toggle;
 mod unset;
+mod zeros;
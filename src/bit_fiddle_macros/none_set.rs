@@ -0,0 +1,63 @@
+/// Macro for checking if no bit is set among single, multiple or range of bits.
+/// It accepts the same patterns as [`is_set!`](crate::is_set), which
+/// checks that all of them are set, and returns a `bool`.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// With no bits selected, like `[4..4]`, the result is `true`.
+/// [`all_unset!`](crate::all_unset) is the same check under another name.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::none_set;
+///
+/// let bitmap: u16 = 0b_1000_0000_0010_0000;
+///
+/// assert!(none_set!(bitmap, [8..15]));
+/// assert!(!none_set!(bitmap, u16, [1, 5, 7]));
+/// assert!(none_set!(bitmap, rev [start = 1, count = 8]));
+/// assert!(none_set!(0b_0111_1111, u8, rev 0));
+/// ```
+#[macro_export]
+macro_rules! none_set {
+    ($( $args: tt )+) => {
+        $crate::__none_set!(none_set, $( $args )+)
+    };
+}
+
+/// Shared implementation of `none_set!` and `all_unset!`.
+///
+/// `$name` is the macro being expanded. The rest are the arguments
+/// passed to the macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __none_set {
+    ($name: ident, $bitmap: tt, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!($name, (bitmap ^ bitmap), [$( $sel )*])) == (bitmap ^ bitmap)
+        }
+    };
+
+    ($name: ident, $bitmap: tt, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!($name, (bitmap ^ bitmap), rev $( $sel )+)) == (bitmap ^ bitmap)
+        }
+    };
+
+    ($name: ident, $bitmap: tt, $bit_pos: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            (bitmap & $crate::__mask!($name, (bitmap ^ bitmap), $bit_pos)) == (bitmap ^ bitmap)
+        }
+    };
+
+    ($name: ident, $bitmap: tt, $ty: ty, $( $sel: tt )+) => {
+        (($bitmap as $ty) & $crate::__mask!($name, (0 as $ty), $( $sel )+)) == (0 as $ty)
+    };
+}
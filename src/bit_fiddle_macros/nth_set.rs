@@ -0,0 +1,70 @@
+/// Macro for finding the `n`th (counting from 0) set bit of a bitmap.
+/// Returns the position as `Option<u32>`, `None` if fewer than `n + 1`
+/// bits are set.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The search can be limited with a trailing selector in brackets, like
+/// the ones [`is_set!`](crate::is_set) accepts. With a `rev` selector
+/// bits are counted from the left and the position is a `rev` position.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::nth_set;
+///
+/// let bitmap: u16 = 0b_0110_1000_0100;
+///
+/// assert_eq!(nth_set!(bitmap, 0), Some(2));
+/// assert_eq!(nth_set!(bitmap, u16, 2), Some(9));
+/// assert_eq!(nth_set!(bitmap, 4), None);
+/// assert_eq!(nth_set!(bitmap, 1, [8..]), Some(10));
+/// assert_eq!(nth_set!(bitmap, u16, 0, rev [..]), Some(5));
+/// assert_eq!(nth_set!(0b_0010_0100, u8, 1, rev [..]), Some(5));
+/// ```
+#[macro_export]
+macro_rules! nth_set {
+    ($bitmap: tt, $n: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::__find_bit!(@nth (bitmap ^ bitmap), (), bitmap, $n)
+        }
+    };
+
+    ($bitmap: tt, $n: expr, [$( $sel: tt )*]) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!(nth_set, (bitmap ^ bitmap), [$( $sel )*]);
+            $crate::__find_bit!(@nth (bitmap ^ bitmap), (), bitmap & mask, $n)
+        }
+    };
+
+    ($bitmap: tt, $n: expr, rev $( $sel: tt )+) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            let mask = $crate::__mask!(nth_set, (bitmap ^ bitmap), rev $( $sel )+);
+            $crate::__find_bit!(@nth (bitmap ^ bitmap), (rev), bitmap & mask, $n)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $n: expr) => {
+        $crate::__find_bit!(@nth (0 as $ty), (), ($bitmap as $ty), $n)
+    };
+
+    ($bitmap: tt, $ty: ty, $n: expr, [$( $sel: tt )*]) => {
+        {
+            let mask = $crate::__mask!(nth_set, (0 as $ty), [$( $sel )*]);
+            $crate::__find_bit!(@nth (0 as $ty), (), ($bitmap as $ty) & mask, $n)
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, $n: expr, rev $( $sel: tt )+) => {
+        {
+            let mask = $crate::__mask!(nth_set, (0 as $ty), rev $( $sel )+);
+            $crate::__find_bit!(@nth (0 as $ty), (rev), ($bitmap as $ty) & mask, $n)
+        }
+    };
+}
@@ -0,0 +1,37 @@
+/// Macro for iterating over the positions of the set bits of a bitmap.
+/// Returns a [`BitPositions`](crate::BitPositions) iterator, which yields
+/// the positions as `u32`, lowest first, and can also be iterated
+/// from the back.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The iteration can be limited with a trailing selector in brackets, like
+/// the ones [`is_set!`](crate::is_set) accepts. With a `rev` selector
+/// positions are counted from the left, so the highest bit comes first.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::ones;
+///
+/// let bitmap: u64 = 0b_0110_1000_0100;
+///
+/// let positions: Vec<u32> = ones!(bitmap).collect();
+/// assert_eq!(positions, [2, 7, 9, 10]);
+///
+/// assert_eq!(ones!(bitmap, u64, [8..]).len(), 2);
+/// assert_eq!(ones!(bitmap, u64).rev().next(), Some(10));
+/// assert!(ones!(bitmap, rev [..]).eq([53, 54, 56, 61]));
+///
+/// let mut channels = 0;
+/// for channel in ones!(0b_1001, u8) {
+///     channels += channel;
+/// }
+/// assert_eq!(channels, 3);
+/// ```
+#[macro_export]
+macro_rules! ones {
+    ($( $args: tt )+) => {
+        $crate::__bit_positions!(ones, set, $( $args )+)
+    };
+}
@@ -0,0 +1,144 @@
+/// Macro for writing a value into a bit field.
+/// It accepts the patterns of [`set!`](crate::set) in brackets,
+/// followed by `= value`, as long as the selected bits are next to each
+/// other. Lists and stepped ranges that leave gaps are not supported.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The bits of the field are cleared, then filled with `value` shifted up to
+/// the lowest bit of the field. Bits of `value` that don't fit in the field
+/// are dropped, so they never bleed into neighbouring bits.
+/// See [`checked_put_bits!`](crate::checked_put_bits) for a variant
+/// rejecting such values instead.
+///
+/// Like with `set!`, the bitmap can be passed with `in` to change it in place,
+/// which returns the bitmap as it was before the change.
+///
+/// ```
+/// # use bit_fiddler::put_bits;
+/// let mut reg: u32 = 0b_1111_0000_1111;
+///
+/// put_bits!(in reg, u32, [4..8] = 0b_1010);
+/// assert_eq!(reg, 0b_1111_1010_1111);
+///
+/// // Only the lowest 4 bits of the value fit in the field.
+/// let res = put_bits!(reg, u32, [start = 4, count = 4] = 0b_1_0110);
+/// assert_eq!(res, 0b_1111_0110_1111);
+///
+/// let res = put_bits!(reg, rev [..4] = 0b_1001);
+/// assert_eq!(res, 0b_1001_0000_0000_0000_0000_1111_1010_1111);
+/// ```
+///
+/// With a type, `value` is converted to it with `as`. Without a type,
+/// `value` must already have the type of the bitmap.
+#[macro_export]
+macro_rules! put_bits {
+    ($bitmap: tt, [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::put_bits!(
+                @put put_bits, bitmap,
+                $crate::__mask!(put_bits, (bitmap ^ bitmap), [$( $sel )*]),
+                $value
+            )
+        }
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            $crate::put_bits!(
+                @put put_bits, bitmap,
+                $crate::__mask!(put_bits, (bitmap ^ bitmap), rev [$( $sel )*]),
+                $value
+            )
+        }
+    };
+
+    (in $bitmap: expr, [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let value = $value;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(put_bits, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::put_bits!(@put put_bits, *bitmap, mask, value);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            #[allow(unused_parens)]
+            let value = $value;
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(put_bits, zero, rev [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::put_bits!(@put put_bits, *bitmap, mask, value);
+            prev
+        }
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*] = $value: expr) => {
+        $crate::put_bits!(
+            @put put_bits, ($bitmap as $ty),
+            $crate::__mask!(put_bits, (0 as $ty), [$( $sel )*]),
+            ($value as $ty)
+        )
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*] = $value: expr) => {
+        $crate::put_bits!(
+            @put put_bits, ($bitmap as $ty),
+            $crate::__mask!(put_bits, (0 as $ty), rev [$( $sel )*]),
+            ($value as $ty)
+        )
+    };
+
+    (in $bitmap: expr, $ty: ty, [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $value as $ty;
+            let mask = $crate::__mask!(put_bits, (0 as $ty), [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::put_bits!(@put put_bits, *bitmap, mask, value);
+            prev
+        }
+    };
+
+    (in $bitmap: expr, $ty: ty, rev [$( $sel: tt )*] = $value: expr) => {
+        {
+            let value = $value as $ty;
+            let mask = $crate::__mask!(put_bits, (0 as $ty), rev [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap = $crate::put_bits!(@put put_bits, *bitmap, mask, value);
+            prev
+        }
+    };
+
+    // Clears the bits of `$mask` in `$bitmap` and puts `$value` there.
+    (@put $name: ident, $bitmap: expr, $mask: expr, $value: expr) => {
+        {
+            let mask = $mask;
+            $crate::__debug_check!($name, $crate::__private::check_contiguous(mask as u128));
+            let shift = mask.trailing_zeros();
+            ($bitmap & !mask) | ($crate::__mask!(@shl $value, shift) & mask)
+        }
+    };
+    // Whether `$value` fits in the bits of `$mask`, i.e. `@put` doesn't drop any of its bits.
+    (@fits $zero: tt, $mask: expr, $value: expr) => {
+        {
+            let mask = $mask;
+            #[allow(unused_parens)]
+            let value = $value;
+            let shift = mask.trailing_zeros();
+            let field = $crate::__mask!(@shr $crate::__mask!(@shl value, shift) & mask, shift);
+            (field & $crate::__mask!(@low $zero, $crate::max_bits!($zero) - shift as usize)) == value
+        }
+    };
+}
@@ -1,7 +1,8 @@
 /// Macro for setting single, multiple or range of bits.
 /// It accepts multiple patterns for different use cases.
 /// It doesn't do any overflow or underflow checks. Behaviour on passing
-/// invalid args is undefined.
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+/// See [`checked_set!`](crate::checked_set) for a variant returning errors instead.
 ///
 /// For all patterns, first arg is the bitmap.
 /// Bitmap can be passed in 2 ways:
@@ -28,7 +29,7 @@
 ///
 /// In the second case, we use `in` before the bitmap.
 /// This pattern will make changes to the bitmap itself and
-/// return nothing.
+/// return the bitmap as it was before the change.
 ///
 /// ```
 /// # macro_rules! dont_test_but_have_syntax_highlighting { () => {
@@ -37,6 +38,97 @@
 /// // bits were set in bitmap
 /// # }}
 /// ```
+///
+/// The bitmap here can be any place expression, such as a struct field,
+/// an indexed element or a dereference. It is evaluated only once.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// struct Regs { ctrl: u8, data: [u8; 4] }
+///
+/// let mut regs = Regs { ctrl: 0, data: [0; 4] };
+/// set!(in regs.ctrl, u8, 3);
+/// set!(in regs.data[2], u8, [1..4]);
+/// assert_eq!(regs.ctrl, 0b_0000_1000);
+/// assert_eq!(regs.data[2], 0b_0000_1110);
+/// ```
+///
+/// Every `in` pattern is a single expression, so it can be used wherever
+/// an expression can, like a `match` arm.
+///
+/// ```
+/// # use bit_fiddler::{set, unset};
+/// enum Op { Enable, Disable }
+///
+/// let mut bitmap: u8 = 0b_1000_0001;
+/// let prev = match Op::Enable {
+///     Op::Enable => set!(in bitmap, u8, [1..3]),
+///     Op::Disable => unset!(in bitmap, u8, [1..3]),
+/// };
+/// assert_eq!(prev, 0b_1000_0001);
+/// assert_eq!(bitmap, 0b_1000_0111);
+/// ```
+///
+/// The type can be left out when the bitmap is a variable whose type
+/// is already known. The type and its bit count are then taken from
+/// the bitmap itself. Literals still need the type to be passed.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// let mut bitmap: u16 = 0;
+///
+/// set!(in bitmap, rev 0);
+/// assert_eq!(bitmap, 0b_1000_0000_0000_0000);
+///
+/// let res = set!(bitmap, [0..4]);
+/// assert_eq!(res, 0b_1000_0000_0000_1111);
+/// ```
+///
+/// Bit positions, range bounds and `start`/`count` values can be any
+/// expression. Each of them is evaluated exactly once.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// let (idx, base, width) = (2, 4, 3);
+///
+/// let mut bitmap = 0;
+/// set!(in bitmap, u32, idx + 1);
+/// set!(in bitmap, u32, [base..base + width]);
+/// assert_eq!(bitmap, 0b_0111_1000);
+/// ```
+///
+/// Lists can mix single bits, ranges, `start`/`count` pairs and
+/// `rev` selectors. They are all folded into one mask, so the bitmap
+/// is updated with a single read-modify-write.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// let mut ctrl: u32 = 0;
+/// set!(in ctrl, u32, [0, 4..8, rev 0, start = 12, count = 3]);
+/// assert_eq!(ctrl, 0b_1000_0000_0000_0000_0111_0000_1111_0001);
+/// ```
+///
+/// Ranges can also be inclusive, like `[4..=7]`, which matches how
+/// datasheets usually describe fields.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// assert_eq!(set!(0, u8, [4..=7]), 0b_1111_0000);
+/// assert_eq!(set!(0, u8, [0..=7]), u8::MAX);
+/// assert_eq!(set!(0, u8, rev [..=1]), 0b_1100_0000);
+/// ```
+///
+/// Ranges can be stepped with `; step = ..` to select every n-th bit.
+/// With `start`/`count`, `count` is the number of bits selected,
+/// each `step` apart.
+///
+/// ```
+/// # use bit_fiddler::set;
+/// assert_eq!(set!(0, u8, [0..8; step = 2]), 0b_0101_0101);
+/// assert_eq!(set!(0, u8, rev [..; step = 4]), 0b_1000_1000);
+/// assert_eq!(set!(0, u8, [start = 1, count = 3, step = 3]), 0b_1001_0010);
+/// ```
+///
 /// Another common thing in these patterns is `rev`.
 /// All patterns support this. Putting `rev` before the
 /// bits being set makes the macro set the bits from left hand side.
@@ -144,160 +236,76 @@
 /// ```
 #[macro_export]
 macro_rules! set {
-    ($bitmap: tt, $ty: ty, [..]) => {
-        {
-            !(0 as $ty)
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, [..]) => {
-        $bitmap |= !(0 as $ty);
-    };
-
-    ($bitmap: tt, $ty: ty, rev [..]) => {
-        {
-            !(0 as $ty)
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [..]) => {
-        $bitmap |= !(0 as $ty);
-    };
-
-    ($bitmap: tt, $ty: ty, [$( $bit_pos: tt),*]) => {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
         {
-            ($bitmap as $ty) | $( ((1 as $ty) << $bit_pos) | )* (0 as $ty)
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap | $crate::__mask!(set, (bitmap ^ bitmap), [$( $sel )*])
         }
     };
 
-    (in $bitmap: ident, $ty: ty, [$( $bit_pos: tt),*]) => {
-        $bitmap |= $( ((1 as $ty) << $bit_pos) | )* (0 as $ty);
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$( $bit_pos: tt),*]) => {
+    ($bitmap: tt, rev $( $sel: tt )+) => {
         {
-            ($bitmap as $ty)
-                | $( ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1)) | )* (0 as $ty)
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap | $crate::__mask!(set, (bitmap ^ bitmap), rev $( $sel )+)
         }
     };
 
-    (in $bitmap: ident, $ty: ty, rev [$( $bit_pos: tt),*]) => {
-        $bitmap |= $( ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1)) | )* (0 as $ty);
-    };
-
-    ($bitmap: tt, $ty: ty, [$start_pos: tt .. $end_pos: tt]) => {
+    ($bitmap: tt, $bit_pos: expr) => {
         {
-            let mask = $crate::mask!([($start_pos)..($end_pos)], ($ty));
-            $bitmap | mask
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap | $crate::__mask!(set, (bitmap ^ bitmap), $bit_pos)
         }
     };
 
-    ($bitmap: tt, $ty: ty, [.. $end_pos: tt]) => {
+    (in $bitmap: expr, [$( $sel: tt )*]) => {
         {
-            let mask = $crate::mask!([..($end_pos)], ($ty));
-            $bitmap | mask
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(set, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap |= mask;
+            prev
         }
     };
 
-    ($bitmap: tt, $ty: ty, [$start_pos: tt ..]) => {
+    (in $bitmap: expr, rev $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!([($start_pos)..], ($ty));
-            $bitmap | mask
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(set, zero, rev $( $sel )+);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap |= mask;
+            prev
         }
     };
 
-    (in $bitmap: ident, $ty: ty, [$start_pos: tt .. $end_pos: tt]) => {
-        let mask = $crate::mask!([($start_pos)..($end_pos)], ($ty));
-        $bitmap |= mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, [.. $end_pos: tt]) => {
-        let mask = $crate::mask!([..($end_pos)], ($ty));
-        $bitmap |= mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, [$start_pos: tt ..]) => {
-        let mask = $crate::mask!([($start_pos)..], ($ty));
-        $bitmap |= mask;
-    };
- 
-    ($bitmap: tt, $ty: ty, [start = $start_pos: tt, count = $count: tt]) => {
-        {
-            let mask = $crate::mask!([start = ($start_pos), count = ($count)], ($ty));
-            $bitmap | mask
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, [start = $start_pos: tt, count = $count: tt]) => {
-        let mask = $crate::mask!([start = ($start_pos), count = ($count)], $ty);
-        $bitmap |= mask;
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt .. $end_pos: tt]) => {
+    (in $bitmap: expr, $bit_pos: expr) => {
         {
-            let mask = $crate::mask!(rev [($start_pos)..($end_pos)], ($ty));
-            $bitmap | mask
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(set, zero, $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap |= mask;
+            prev
         }
     };
 
-    ($bitmap: tt, $ty: ty, rev [.. $end_pos: tt]) => {
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!(rev [..($end_pos)], ($ty));
-            $bitmap | mask
+            ($bitmap as $ty) | $crate::__mask!(set, (0 as $ty), $( $sel )+)
         }
     };
 
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt ..]) => {
+    (in $bitmap: expr, $ty: ty, $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!(rev [($start_pos)..], ($ty));
-            $bitmap | mask
+            let mask = $crate::__mask!(set, (0 as $ty), $( $sel )+);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap |= mask;
+            prev
         }
     };
- 
-    (in $bitmap: ident, $ty: ty, rev [$start_pos: tt .. $end_pos: tt]) => {
-        let mask = $crate::mask!(rev [($start_pos)..($end_pos)], ($ty));
-        $bitmap |= mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [.. $end_pos: tt]) => {
-        let mask = $crate::mask!(rev [..($end_pos)], ($ty));
-        $bitmap |= mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [$start_pos: tt ..]) => {
-        let mask = $crate::mask!(rev [($start_pos)..], ($ty));
-        $bitmap |= mask;
-    };
- 
-    ($bitmap: tt, $ty: ty, rev [start = $start_pos: tt, count = $count: tt]) => {
-        {
-            let mask = $crate::mask!(rev [start = ($start_pos), count = ($count)], ($ty));
-            $bitmap | mask
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [start = $start_pos: tt, count = $count: tt]) => {
-        let mask = $crate::mask!(rev [start = ($start_pos), count = ($count)], ($ty));
-        $bitmap |= mask;
-    };
-
-    ($bitmap: tt, $ty: ty, rev $bit_pos: tt) => {
-        {
-            ($bitmap as $ty) | ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1))
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev $bit_pos: tt) => {
-        $bitmap |= ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1));
-    };
-
-    ($bitmap: tt, $ty: ty, $bit_pos: tt) => {
-        {
-            ($bitmap as $ty) | ((1 as $ty) << $bit_pos)
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, $bit_pos: tt) => {
-        $bitmap |= (1 as $ty) << $bit_pos;
-    };
 }
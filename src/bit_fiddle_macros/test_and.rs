@@ -0,0 +1,102 @@
+/// Shared implementation of `test_and_set!`, `test_and_unset!` and
+/// `test_and_toggle!`.
+///
+/// `$name` is the macro being expanded and `$op` is `set`, `unset`
+/// or `toggle`. The rest are the arguments passed to the macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_and {
+    ($name: ident, $op: ident, in $bitmap: expr, [$( $sel: tt )*]) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!($name, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, rev [$( $sel: tt )*]) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!($name, zero, rev [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, rev $bit_pos: expr) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!($name, zero, rev $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev != zero
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, $bit_pos: expr) => {
+        {
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!($name, zero, $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev != zero
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, $ty: ty, [$( $sel: tt )*]) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, $ty: ty, rev [$( $sel: tt )*]) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), rev [$( $sel )*]);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, $ty: ty, rev $bit_pos: expr) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), rev $bit_pos);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev != 0
+        }
+    };
+
+    ($name: ident, $op: ident, in $bitmap: expr, $ty: ty, $bit_pos: expr) => {
+        {
+            let mask = $crate::__mask!($name, (0 as $ty), $bit_pos);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap & mask;
+            $crate::__test_and!(@$op bitmap, mask);
+            prev != 0
+        }
+    };
+
+    (@set $bitmap: ident, $mask: ident) => {
+        *$bitmap |= $mask
+    };
+    (@unset $bitmap: ident, $mask: ident) => {
+        *$bitmap &= !$mask
+    };
+    (@toggle $bitmap: ident, $mask: ident) => {
+        *$bitmap ^= $mask
+    };
+}
@@ -0,0 +1,31 @@
+/// Macro for setting single, multiple or range of bits in place and
+/// returning their previous state in one step.
+/// It accepts the same patterns as the `in` forms of [`set!`](crate::set).
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// For a single bit, like `3` or `rev 3`, it returns whether the bit was
+/// set as `bool`. For lists and ranges in brackets it returns the selected
+/// bits of the bitmap before the change, with the other bits cleared.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::test_and_set;
+///
+/// let mut bitmap: u8 = 0b_0000_0100;
+///
+/// assert!(!test_and_set!(in bitmap, u8, 3));
+/// assert!(test_and_set!(in bitmap, 3));
+/// assert_eq!(bitmap, 0b_0000_1100);
+///
+/// assert_eq!(test_and_set!(in bitmap, [0..4]), 0b_0000_1100);
+/// assert_eq!(test_and_set!(in bitmap, u8, rev [..2]), 0);
+/// assert_eq!(bitmap, 0b_1100_1111);
+/// ```
+#[macro_export]
+macro_rules! test_and_set {
+    ($( $args: tt )+) => {
+        $crate::__test_and!(test_and_set, set, $( $args )+)
+    };
+}
@@ -0,0 +1,31 @@
+/// Macro for unsetting single, multiple or range of bits in place and
+/// returning their previous state in one step.
+/// It accepts the same patterns as the `in` forms of [`unset!`](crate::unset).
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// For a single bit, like `3` or `rev 3`, it returns whether the bit was
+/// set as `bool`. For lists and ranges in brackets it returns the selected
+/// bits of the bitmap before the change, with the other bits cleared.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::test_and_unset;
+///
+/// let mut bitmap: u8 = 0b_1111_0100;
+///
+/// assert!(test_and_unset!(in bitmap, u8, 2));
+/// assert!(!test_and_unset!(in bitmap, 2));
+/// assert_eq!(bitmap, 0b_1111_0000);
+///
+/// assert_eq!(test_and_unset!(in bitmap, [2..6]), 0b_0011_0000);
+/// assert!(test_and_unset!(in bitmap, u8, rev 0));
+/// assert_eq!(bitmap, 0b_0100_0000);
+/// ```
+#[macro_export]
+macro_rules! test_and_unset {
+    ($( $args: tt )+) => {
+        $crate::__test_and!(test_and_unset, unset, $( $args )+)
+    };
+}
@@ -0,0 +1,118 @@
+/// Macro for collecting the positions of the set bits of a bitmap.
+/// It accepts the same patterns as [`ones!`](crate::ones), except
+/// for single bit selectors, and the positions are in the same order.
+///
+/// On its own, it returns the positions as a `Vec<u32>`. This form needs
+/// the `alloc` feature.
+///
+/// Followed by `=> buf`, it writes the positions to the start of `buf`,
+/// a `&mut [u32]`, and returns the count written as `usize`. Positions
+/// that don't fit in `buf` are left out; a buffer of
+/// [`max_bits!`](crate::max_bits) entries always fits all of them.
+///
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::to_positions;
+///
+/// let bitmap: u32 = 0b_1001_0110;
+///
+/// let mut buf = [0; 32];
+/// let count = to_positions!(bitmap, u32 => &mut buf);
+/// assert_eq!(buf[..count], [1, 2, 4, 7]);
+///
+/// let count = to_positions!(bitmap, rev [..28] => &mut buf);
+/// assert_eq!(buf[..count], [24, 27]);
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(to_positions!(bitmap, [2..]), [2, 4, 7]);
+/// ```
+#[macro_export]
+macro_rules! to_positions {
+    ($bitmap: tt) => {
+        $crate::to_positions!(@vec $crate::ones!($bitmap))
+    };
+
+    ($bitmap: tt, [$( $sel: tt )*]) => {
+        $crate::to_positions!(@vec $crate::__bit_positions!(to_positions, set, $bitmap, [$( $sel )*]))
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*]) => {
+        $crate::to_positions!(@vec $crate::__bit_positions!(to_positions, set, $bitmap, rev [$( $sel )*]))
+    };
+
+    ($bitmap: tt => $buf: expr) => {
+        $crate::__private::fill_positions($crate::ones!($bitmap), $buf)
+    };
+
+    ($bitmap: tt, [$( $sel: tt )*] => $buf: expr) => {
+        $crate::__private::fill_positions(
+            $crate::__bit_positions!(to_positions, set, $bitmap, [$( $sel )*]),
+            $buf,
+        )
+    };
+
+    ($bitmap: tt, rev [$( $sel: tt )*] => $buf: expr) => {
+        $crate::__private::fill_positions(
+            $crate::__bit_positions!(to_positions, set, $bitmap, rev [$( $sel )*]),
+            $buf,
+        )
+    };
+
+    ($bitmap: tt, $ty: ty) => {
+        $crate::to_positions!(@vec $crate::ones!($bitmap, $ty))
+    };
+
+    ($bitmap: tt, $ty: ty => $buf: expr) => {
+        $crate::__private::fill_positions($crate::ones!($bitmap, $ty), $buf)
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*]) => {
+        $crate::to_positions!(@vec $crate::__bit_positions!(to_positions, set, $bitmap, $ty, [$( $sel )*]))
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*]) => {
+        $crate::to_positions!(@vec $crate::__bit_positions!(to_positions, set, $bitmap, $ty, rev [$( $sel )*]))
+    };
+
+    ($bitmap: tt, $ty: ty, [$( $sel: tt )*] => $buf: expr) => {
+        $crate::__private::fill_positions(
+            $crate::__bit_positions!(to_positions, set, $bitmap, $ty, [$( $sel )*]),
+            $buf,
+        )
+    };
+
+    ($bitmap: tt, $ty: ty, rev [$( $sel: tt )*] => $buf: expr) => {
+        $crate::__private::fill_positions(
+            $crate::__bit_positions!(to_positions, set, $bitmap, $ty, rev [$( $sel )*]),
+            $buf,
+        )
+    };
+
+    (@vec $positions: expr) => {
+        $crate::__positions_vec!($positions)
+    };
+}
+
+/// Collects the positions for the forms of `to_positions!` returning a `Vec`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __positions_vec {
+    ($positions: expr) => {
+        $crate::__private::Iterator::collect::<$crate::__private::Vec<u32>>($positions)
+    };
+}
+
+/// Without `alloc`, points to the feature instead of failing to find `Vec`.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __positions_vec {
+    ($positions: expr) => {
+        compile_error!("to_positions! without a buffer needs the `alloc` feature")
+    };
+}
@@ -1,7 +1,8 @@
 /// Macro for usetting single, multiple or range of bits.
 /// It accepts multiple patterns for different use cases.
 /// It doesn't do any overflow or underflow checks. Behaviour on passing
-/// invalid args is undefined.
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+/// See [`checked_unset!`](crate::checked_unset) for a variant returning errors instead.
 ///
 /// For all patterns, first arg is the bitmap.
 /// Bitmap can be passed in 2 ways:
@@ -28,7 +29,7 @@
 ///
 /// In the second case, we use `in` before the bitmap.
 /// This pattern will make changes to the bitmap itself and
-/// return nothing.
+/// return the bitmap as it was before the change.
 ///
 /// ```
 /// # macro_rules! dont_test_but_have_syntax_highlighting { () => {
@@ -37,6 +38,36 @@
 /// // bits were unset in bitmap
 /// # }}
 /// ```
+///
+/// The bitmap here can be any place expression, such as a struct field,
+/// an indexed element or a dereference. It is evaluated only once.
+///
+/// ```
+/// # use bit_fiddler::unset;
+/// struct Regs { ctrl: u8, data: [u8; 4] }
+///
+/// let mut regs = Regs { ctrl: 0b_1111_1111, data: [0b_1111_1111; 4] };
+/// unset!(in regs.ctrl, u8, 3);
+/// unset!(in regs.data[2], u8, [1..4]);
+/// assert_eq!(regs.ctrl, 0b_1111_0111);
+/// assert_eq!(regs.data[2], 0b_1111_0001);
+/// ```
+///
+/// The type can be left out when the bitmap is a variable whose type
+/// is already known. The type and its bit count are then taken from
+/// the bitmap itself. Literals still need the type to be passed.
+///
+/// ```
+/// # use bit_fiddler::unset;
+/// let mut bitmap: u16 = u16::MAX;
+///
+/// unset!(in bitmap, rev 0);
+/// assert_eq!(bitmap, 0b_0111_1111_1111_1111);
+///
+/// let res = unset!(bitmap, [0..4]);
+/// assert_eq!(res, 0b_0111_1111_1111_0000);
+/// ```
+///
 /// Another common thing in these patterns is `rev`.
 /// All patterns support this. Putting `rev` before the
 /// bits being unset makes the macro unset the bits from left hand side.
@@ -144,164 +175,76 @@
 /// ```
 #[macro_export]
 macro_rules! unset {
-    ($bitmap: tt, $ty: ty, [..]) => {
+    ($bitmap: tt, [$( $sel: tt )*]) => {
         {
-            (0 as $ty)
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap & !$crate::__mask!(unset, (bitmap ^ bitmap), [$( $sel )*])
         }
     };
 
-    (in $bitmap: ident, $ty: ty, [..]) => {
+    ($bitmap: tt, rev $( $sel: tt )+) => {
         {
-            $bitmap = (0 as $ty);
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap & !$crate::__mask!(unset, (bitmap ^ bitmap), rev $( $sel )+)
         }
     };
 
-    ($bitmap: tt, $ty: ty, rev [..]) => {
+    ($bitmap: tt, $bit_pos: expr) => {
         {
-            (0 as $ty)
+            #[allow(unused_parens)]
+            let bitmap = $bitmap;
+            bitmap & !$crate::__mask!(unset, (bitmap ^ bitmap), $bit_pos)
         }
     };
 
-    (in $bitmap: ident, $ty: ty, rev [..]) => {
+    (in $bitmap: expr, [$( $sel: tt )*]) => {
         {
-            $bitmap = (0 as $ty);
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(unset, zero, [$( $sel )*]);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap &= !mask;
+            prev
         }
     };
 
-    ($bitmap: tt, $ty: ty, [$( $bit_pos: tt),*]) => {
+    (in $bitmap: expr, rev $( $sel: tt )+) => {
         {
-            ($bitmap as $ty) & !($( ((1 as $ty) << $bit_pos) | )* (0 as $ty))
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(unset, zero, rev $( $sel )+);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap &= !mask;
+            prev
         }
     };
 
-    (in $bitmap: ident, $ty: ty, [$( $bit_pos: tt),*]) => {
-        $bitmap &= !($( ((1 as $ty) << $bit_pos) | )* (0 as $ty));
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$( $bit_pos: tt),*]) => {
+    (in $bitmap: expr, $bit_pos: expr) => {
         {
-            ($bitmap as $ty)
-                & !($( ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1)) | )* (0 as $ty))
+            let zero = $crate::__private::zero_of(|| $bitmap);
+            let mask = $crate::__mask!(unset, zero, $bit_pos);
+            let bitmap = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap &= !mask;
+            prev
         }
     };
 
-    (in $bitmap: ident, $ty: ty, rev [$( $bit_pos: tt),*]) => {
-        $bitmap &= !($( ((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1)) | )* (0 as $ty));
-    };
-
-    ($bitmap: tt, $ty: ty, [$start_pos: tt .. $end_pos: tt]) => {
+    ($bitmap: tt, $ty: ty, $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!([($start_pos)..($end_pos)], ($ty));
-            $bitmap & !mask
+            ($bitmap as $ty) & !$crate::__mask!(unset, (0 as $ty), $( $sel )+)
         }
     };
 
-    ($bitmap: tt, $ty: ty, [$start_pos: tt ..]) => {
+    (in $bitmap: expr, $ty: ty, $( $sel: tt )+) => {
         {
-            let mask = $crate::mask!([($start_pos)..], ($ty));
-            $bitmap & !mask
+            let mask = $crate::__mask!(unset, (0 as $ty), $( $sel )+);
+            let bitmap: &mut $ty = &mut $bitmap;
+            let prev = *bitmap;
+            *bitmap &= !mask;
+            prev
         }
     };
-
-    ($bitmap: tt, $ty: ty, [.. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!([..($end_pos)], ($ty));
-            $bitmap & !mask
-        }
-    };
- 
-    (in $bitmap: ident, $ty: ty, [$start_pos: tt .. $end_pos: tt]) => {
-        let mask = $crate::mask!([($start_pos)..($end_pos)], ($ty));
-        $bitmap &= !mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, [.. $end_pos: tt]) => {
-        let mask = $crate::mask!([..($end_pos)], ($ty));
-        $bitmap &= !mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, [$start_pos: tt ..]) => {
-        let mask = $crate::mask!([($start_pos)..], ($ty));
-        $bitmap &= !mask;
-    };
-
-    ($bitmap: tt, $ty: ty, [start = $start_pos: tt, count = $count: tt]) => {
-        {
-            let mask = $crate::mask!([start = ($start_pos), count = ($count)], ($ty));
-            $bitmap & !mask
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, [start = $start_pos: tt, count = $count: tt]) => {
-        let mask = $crate::mask!([start = ($start_pos), count = ($count)], $ty);
-        $bitmap &= !mask;
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt .. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!(rev [($start_pos)..($end_pos)], ($ty));
-            $bitmap & !mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [$start_pos: tt ..]) => {
-        {
-            let mask = $crate::mask!(rev [($start_pos)..], ($ty));
-            $bitmap & !mask
-        }
-    };
-
-    ($bitmap: tt, $ty: ty, rev [.. $end_pos: tt]) => {
-        {
-            let mask = $crate::mask!(rev [..($end_pos)], ($ty));
-            $bitmap & !mask
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [$start_pos: tt .. $end_pos: tt]) => {
-        let mask = $crate::mask!(rev [($start_pos)..($end_pos)], ($ty));
-        $bitmap &= !mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [.. $end_pos: tt]) => {
-        let mask = $crate::mask!(rev [..($end_pos)], ($ty));
-        $bitmap &= !mask;
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [$start_pos: tt ..]) => {
-        let mask = $crate::mask!(rev [($start_pos)..], ($ty));
-        $bitmap &= !mask;
-    };
-
-    ($bitmap: tt, $ty: ty, rev [start = $start_pos: tt, count = $count: tt]) => {
-        {
-            let mask = $crate::mask!(rev [start = ($start_pos), count = ($count)], ($ty));
-            $bitmap & !mask
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev [start = $start_pos: tt, count = $count: tt]) => {
-        let mask = $crate::mask!(rev [start = ($start_pos), count = ($count)], ($ty));
-        $bitmap &= !mask;
-    };
-
-    ($bitmap: tt, $ty: ty, rev $bit_pos: tt) => {
-        {
-            ($bitmap as $ty) & !((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1))
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, rev $bit_pos: tt) => {
-        $bitmap &= !((1 as $ty) << ($crate::max_bits!(type = ($ty)) - $bit_pos - 1));
-    };
-
-    ($bitmap: tt, $ty: ty, $bit_pos: tt) => {
-        {
-            ($bitmap as $ty) & !((1 as $ty) << $bit_pos)
-        }
-    };
-
-    (in $bitmap: ident, $ty: ty, $bit_pos: tt) => {
-        $bitmap &= !((1 as $ty) << $bit_pos);
-    };
 }
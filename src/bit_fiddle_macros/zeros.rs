@@ -0,0 +1,32 @@
+/// Macro for iterating over the positions of the unset bits of a bitmap.
+/// Returns a [`BitPositions`](crate::BitPositions) iterator, which yields
+/// the positions as `u32`, lowest first, and can also be iterated
+/// from the back.
+/// It doesn't do any overflow or underflow checks. Behaviour on passing
+/// invalid args is undefined, unless the `debug-checks` feature is enabled.
+///
+/// The iteration can be limited with a trailing selector in brackets, like
+/// the ones [`is_set!`](crate::is_set) accepts. With a `rev` selector
+/// positions are counted from the left, so the highest bit comes first.
+///
+/// # Example
+///
+/// ```
+/// use bit_fiddler::zeros;
+///
+/// let bitmap: u16 = 0b_1111_1001_0111_1111;
+///
+/// let positions: Vec<u32> = zeros!(bitmap).collect();
+/// assert_eq!(positions, [7, 9, 10]);
+///
+/// assert_eq!(zeros!(bitmap, u16, [8..]).len(), 2);
+/// assert_eq!(zeros!(bitmap, u16).rev().next(), Some(10));
+/// assert!(zeros!(bitmap, rev [..8]).eq([5, 6]));
+/// assert!(zeros!(0xff, u8).next().is_none());
+/// ```
+#[macro_export]
+macro_rules! zeros {
+    ($( $args: tt )+) => {
+        $crate::__bit_positions!(zeros, unset, $( $args )+)
+    };
+}
@@ -0,0 +1,95 @@
+use core::fmt;
+
+use crate::__private::Message;
+
+/// Error returned by the `checked_*` macros when a bit position
+/// or range doesn't fit in the bitmap.
+///
+/// All positions are reported as they were passed to the macro,
+/// i.e. `rev` positions are still counted from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BitError {
+    /// Bit position is not less than the bit count of the type.
+    PositionOutOfRange { pos: usize, max_bits: usize },
+    /// `rev` bit position is not less than the bit count of the type,
+    /// so it can't be counted from the left hand side.
+    RevUnderflow { pos: usize, max_bits: usize },
+    /// Range starts after it ends.
+    StartAfterEnd { start: usize, end: usize },
+    /// Range ends past the bit count of the type.
+    /// `end` is the last bit of the range for `..=` ranges.
+    EndOutOfRange { end: usize, max_bits: usize },
+    /// `start + count` overflows or goes past the bit count of the type.
+    CountOverflow {
+        start: usize,
+        count: usize,
+        max_bits: usize,
+    },
+    /// Step of a stepped range is zero.
+    ZeroStep,
+    /// Value doesn't fit in the bits of the field it is written to.
+    ValueTooWide { field_bits: usize },
+    /// Bits selected as a field aren't next to each other.
+    NonContiguousField,
+}
+
+impl BitError {
+    /// Appends the description of the error to `msg`.
+    ///
+    /// Shared by the `Display` impl and the `debug-checks` panics,
+    /// which need to build the message in a `const fn`.
+    pub(crate) const fn describe(self, msg: Message) -> Message {
+        match self {
+            BitError::PositionOutOfRange { pos, max_bits } => msg
+                .push_str("bit position ")
+                .push_usize(pos)
+                .push_str(" is out of range for a type of ")
+                .push_usize(max_bits)
+                .push_str(" bits"),
+            BitError::RevUnderflow { pos, max_bits } => msg
+                .push_str("rev bit position ")
+                .push_usize(pos)
+                .push_str(" is out of range for a type of ")
+                .push_usize(max_bits)
+                .push_str(" bits"),
+            BitError::StartAfterEnd { start, end } => msg
+                .push_str("range start ")
+                .push_usize(start)
+                .push_str(" is greater than range end ")
+                .push_usize(end),
+            BitError::EndOutOfRange { end, max_bits } => msg
+                .push_str("range end ")
+                .push_usize(end)
+                .push_str(" is out of range for a type of ")
+                .push_usize(max_bits)
+                .push_str(" bits"),
+            BitError::CountOverflow {
+                start,
+                count,
+                max_bits,
+            } => msg
+                .push_usize(count)
+                .push_str(" bits starting at ")
+                .push_usize(start)
+                .push_str(" don't fit in a type of ")
+                .push_usize(max_bits)
+                .push_str(" bits"),
+            BitError::ZeroStep => msg.push_str("range step must be greater than zero"),
+            BitError::ValueTooWide { field_bits } => msg
+                .push_str("value doesn't fit in a field of ")
+                .push_usize(field_bits)
+                .push_str(" bits"),
+            BitError::NonContiguousField => msg.push_str("selected bits don't form a contiguous field"),
+        }
+    }
+}
+
+impl fmt::Display for BitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.describe(Message::new()).as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitError {}
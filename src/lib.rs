@@ -6,9 +6,42 @@
 //! These include operations on a single bit, range of bits, etc.
 //! See macro docs for more details.
 //!
+//! Bit positions and range bounds can be any expression,
+//! like `idx + 1` or `[base..base + width]`, and are evaluated once.
+//!
 //! Macros in this crate don't do overflow/underflow checks.
 //! If invalid args are supplied, behaviour depends on the underlying
 //! operators and may panic.
+//! Use the `checked_*` variants of the macros, like [`checked_set!`],
+//! when bit positions come from untrusted input. They return a [`BitError`]
+//! for invalid positions and ranges instead.
+//!
+//! [`mask!`], [`max_bits!`], [`is_set!`], [`any_set!`], [`none_set!`],
+//! [`all_unset!`], [`missing_bits!`], [`get_bits!`], [`count_set!`],
+//! [`count_unset!`], [`first_set!`], [`last_set!`], [`first_unset!`],
+//! [`last_unset!`], [`nth_set!`] and the forms of [`set!`], [`unset!`],
+//! [`toggle!`], [`put_bits!`], [`assign!`] and [`bits!`] returning the new
+//! bitmap can be used in `const` and `static` items and in `const fn`s.
+//!
+//! ```
+//! use bit_fiddler::{mask, set};
+//!
+//! const IRQ_MASK: u32 = mask!([4..8], u32) | set!(0, u32, [0, 2]);
+//! assert_eq!(IRQ_MASK, 0b_1111_0101);
+//! ```
+//!
+//! # Features
+//!
+//! The crate is `#![no_std]`, so all macros can be used in `no_std` crates.
+//!
+//! - `alloc`: Enables the form of [`to_positions!`] returning a `Vec`.
+//! - `std`: Implements `std::error::Error` for [`BitError`].
+//!   Implies `alloc`.
+//! - `debug-checks`: Makes all macros validate bit positions and ranges
+//!   when debug assertions are enabled, like `debug_assert!`. Invalid args
+//!   panic with a message naming the macro and the offending value.
+//!   Without this feature, no checks are added to the expansions.
+//!   In const contexts, a failed check is a compile error.
 //!
 //! # Example
 //!
@@ -19,5 +52,42 @@
 //! set!(in bitmap, u8, [3..6]);
 //! assert_eq!(bitmap, 0b_0011_1000);
 //! ```
+//!
+//! # Migrating from 2.x
+//!
+//! The `in` forms of [`set!`], [`unset!`] and [`toggle!`] now return the
+//! bitmap as it was before the change instead of `()`. Calls used where `()`
+//! is expected, like the branches of an `if` or the arms of a `match` whose
+//! value isn't used, need a `;` after them.
+//!
+//! ```
+//! use bit_fiddler::{set, unset};
+//!
+//! let mut cfg: u32 = 0;
+//! let enabled = true;
+//!
+//! // 2.x: if enabled { set!(in cfg, u32, 5) } else { unset!(in cfg, u32, 5) }
+//! if enabled { set!(in cfg, u32, 5); } else { unset!(in cfg, u32, 5); }
+//! assert_eq!(cfg, 0b_0010_0000);
+//! ```
+//!
+//! The minimum supported Rust version is now 1.71, which the const evaluable
+//! macros need.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod bit_fiddle_macros;
+mod error;
+mod positions;
+
+pub use error::BitError;
+pub use positions::BitPositions;
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
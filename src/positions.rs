@@ -0,0 +1,75 @@
+use core::iter::FusedIterator;
+
+/// Iterator over the positions of the set or unset bits of a bitmap,
+/// returned by [`ones!`](crate::ones) and [`zeros!`](crate::zeros).
+///
+/// Positions are yielded lowest first. When created with a `rev`
+/// selector, positions are counted from the left and the highest bit
+/// comes first, so positions are still yielded in ascending order.
+///
+/// Each step clears the bit it yields, so iterating takes as many steps
+/// as there are bits to yield, not as many as the bitmap is wide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitPositions {
+    /// Bits left to yield, zero extended to 128 bits.
+    pub(crate) bits: u128,
+    /// Bit count of the bitmap type.
+    pub(crate) max_bits: u32,
+    /// Whether positions are counted from the left.
+    pub(crate) rev: bool,
+}
+
+impl BitPositions {
+    /// Removes the lowest bit left and returns its position.
+    fn pop_low(&mut self) -> u32 {
+        let pos = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        pos
+    }
+
+    /// Removes the highest bit left and returns its position.
+    fn pop_high(&mut self) -> u32 {
+        let pos = 127 - self.bits.leading_zeros();
+        self.bits ^= 1 << pos;
+        pos
+    }
+}
+
+impl Iterator for BitPositions {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.bits == 0 {
+            None
+        } else if self.rev {
+            Some(self.max_bits - 1 - self.pop_high())
+        } else {
+            Some(self.pop_low())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+}
+
+impl DoubleEndedIterator for BitPositions {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.bits == 0 {
+            None
+        } else if self.rev {
+            Some(self.max_bits - 1 - self.pop_low())
+        } else {
+            Some(self.pop_high())
+        }
+    }
+}
+
+impl ExactSizeIterator for BitPositions {}
+
+impl FusedIterator for BitPositions {}
@@ -0,0 +1,363 @@
+//! Helpers used by the macro expansions.
+//! Not part of the public API.
+
+use core::convert::TryFrom;
+
+use crate::{BitError, BitPositions};
+
+#[cfg(feature = "alloc")]
+pub use alloc::vec::Vec;
+pub use core::iter::Iterator;
+pub use core::mem::replace;
+pub use core::option::Option::{None, Some};
+pub use core::result::Result::{Err, Ok};
+
+/// Returns the bit count of the type of `bitmap`.
+#[inline(always)]
+pub const fn bits_of<T>(_bitmap: &T) -> usize {
+    core::mem::size_of::<T>() * 8
+}
+
+/// Returns zero of the type `bitmap` returns, without calling it.
+///
+/// Lets the `in` forms build their mask before borrowing the bitmap,
+/// so the mask can read it, without evaluating the bitmap twice.
+#[inline(always)]
+pub fn zero_of<T: Default>(_bitmap: impl FnOnce() -> T) -> T {
+    T::default()
+}
+
+/// Creates an iterator over the positions of the set bits of `bits`,
+/// a bitmap of a `max_bits` bit type converted with `as u128`.
+///
+/// Bits past `max_bits`, set by sign extension, are dropped.
+pub const fn bit_positions(bits: u128, max_bits: usize, rev: bool) -> BitPositions {
+    BitPositions {
+        bits: bits & (!0 >> (128 - max_bits)),
+        max_bits: max_bits as u32,
+        rev,
+    }
+}
+
+/// Writes the positions yielded by `positions` to the start of `buf`,
+/// stopping when either runs out, and returns how many were written.
+pub fn fill_positions(positions: BitPositions, buf: &mut [u32]) -> usize {
+    let mut written = 0;
+    for (slot, pos) in buf.iter_mut().zip(positions) {
+        *slot = pos;
+        written += 1;
+    }
+    written
+}
+
+/// Integer types accepted as bit positions by `from_positions!` and the
+/// `checked_*` macros, by value or by reference.
+pub trait Position {
+    /// Converts the position to `usize`, saturating values that don't fit,
+    /// like negative ones, to `usize::MAX` so they fail the range check.
+    fn to_position(self) -> usize;
+}
+
+macro_rules! impl_position {
+    ($( $ty: ty ),*) => {
+        $(
+            impl Position for $ty {
+                #[inline(always)]
+                fn to_position(self) -> usize {
+                    core::convert::TryFrom::try_from(self).unwrap_or(usize::MAX)
+                }
+            }
+
+            impl Position for &$ty {
+                #[inline(always)]
+                fn to_position(self) -> usize {
+                    (*self).to_position()
+                }
+            }
+        )*
+    };
+}
+
+impl_position!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Integer types accepted as values by the typed forms of `checked_put_bits!`.
+pub trait Value {
+    /// Widens the value to `i128`, or to `u128` for unsigned types,
+    /// so no bits are lost before it is checked against the type of the bitmap.
+    fn widen(self) -> Wide;
+}
+
+/// A value widened by [`Value::widen`].
+pub enum Wide {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+macro_rules! impl_value {
+    ($variant: ident, $wide: ty, $( $ty: ty ),*) => {
+        $(
+            impl Value for $ty {
+                #[inline(always)]
+                fn widen(self) -> Wide {
+                    Wide::$variant(self as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_value!(Signed, i128, i8, i16, i32, i64, i128, isize);
+impl_value!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+/// Converts `value` to `T`, or returns `None` if it doesn't fit.
+#[inline(always)]
+pub fn fit<T, V>(value: V) -> Option<T>
+where
+    T: TryFrom<i128> + TryFrom<u128>,
+    V: Value,
+{
+    match value.widen() {
+        Wide::Signed(value) => T::try_from(value).ok(),
+        Wide::Unsigned(value) => T::try_from(value).ok(),
+    }
+}
+
+/// Sets the bits at `positions` of a `max_bits` bit bitmap, returned
+/// zero extended to 128 bits. With `rev`, positions are counted from the left.
+pub fn from_positions<I>(positions: I, max_bits: usize, rev: bool) -> Result<u128, BitError>
+where
+    I: IntoIterator,
+    I::Item: Position,
+{
+    let mut bits = 0;
+    for pos in positions {
+        let pos = pos.to_position();
+        if rev {
+            check_rev_bit(pos, max_bits)?;
+            bits |= 1 << (max_bits - 1 - pos);
+        } else {
+            check_bit(pos, max_bits)?;
+            bits |= 1 << pos;
+        }
+    }
+    Ok(bits)
+}
+
+/// Checks that `pos` is a valid bit position for a `max_bits` bit type.
+pub const fn check_bit(pos: usize, max_bits: usize) -> Result<(), BitError> {
+    if pos < max_bits {
+        Ok(())
+    } else {
+        Err(BitError::PositionOutOfRange { pos, max_bits })
+    }
+}
+
+/// Same as [`check_bit`] but for positions counted from the left.
+pub const fn check_rev_bit(pos: usize, max_bits: usize) -> Result<(), BitError> {
+    if pos < max_bits {
+        Ok(())
+    } else {
+        Err(BitError::RevUnderflow { pos, max_bits })
+    }
+}
+
+/// Checks every position of a list with [`check_bit`].
+pub const fn check_bits(positions: &[usize], max_bits: usize) -> Result<(), BitError> {
+    let mut i = 0;
+    while i < positions.len() {
+        if let Err(err) = check_bit(positions[i], max_bits) {
+            return Err(err);
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Checks every position of a list with [`check_rev_bit`].
+pub const fn check_rev_bits(positions: &[usize], max_bits: usize) -> Result<(), BitError> {
+    let mut i = 0;
+    while i < positions.len() {
+        if let Err(err) = check_rev_bit(positions[i], max_bits) {
+            return Err(err);
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Checks that `start..end` is a valid range for a `max_bits` bit type.
+pub const fn check_range(start: usize, end: usize, max_bits: usize) -> Result<(), BitError> {
+    if start > end {
+        Err(BitError::StartAfterEnd { start, end })
+    } else if end > max_bits {
+        Err(BitError::EndOutOfRange { end, max_bits })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `start..=end` is a valid range for a `max_bits` bit type.
+/// `start` may be one past `end`, for an empty range.
+pub const fn check_inclusive_range(start: usize, end: usize, max_bits: usize) -> Result<(), BitError> {
+    if start > 0 && start - 1 > end {
+        Err(BitError::StartAfterEnd { start, end })
+    } else if end >= max_bits {
+        Err(BitError::EndOutOfRange { end, max_bits })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `count` bits starting at `start` fit in a `max_bits` bit type
+/// and returns the end of the range.
+pub const fn check_count(start: usize, count: usize, max_bits: usize) -> Result<usize, BitError> {
+    match start.checked_add(count) {
+        Some(end) if end <= max_bits => Ok(end),
+        _ => Err(BitError::CountOverflow {
+            start,
+            count,
+            max_bits,
+        }),
+    }
+}
+
+/// Checks that `start..end` stepped by `step` is a valid range
+/// for a `max_bits` bit type and returns the number of bits it selects.
+pub const fn check_stepped_range(
+    start: usize,
+    end: usize,
+    step: usize,
+    max_bits: usize,
+) -> Result<usize, BitError> {
+    if let Err(err) = check_range(start, end, max_bits) {
+        return Err(err);
+    }
+    if step == 0 {
+        return Err(BitError::ZeroStep);
+    }
+    Ok(stepped_count(start, end, step))
+}
+
+/// Checks that `count` bits, `step` apart, starting at `start`
+/// fit in a `max_bits` bit type.
+pub const fn check_stepped_count(
+    start: usize,
+    count: usize,
+    step: usize,
+    max_bits: usize,
+) -> Result<(), BitError> {
+    if step == 0 {
+        return Err(BitError::ZeroStep);
+    }
+    if count == 0 {
+        return match check_count(start, 0, max_bits) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        };
+    }
+    // The last bit is at `start + (count - 1) * step`.
+    let last = match (count - 1).checked_mul(step) {
+        Some(span) => start.checked_add(span),
+        None => None,
+    };
+    match last {
+        Some(last) if last < max_bits => Ok(()),
+        _ => Err(BitError::CountOverflow {
+            start,
+            count,
+            max_bits,
+        }),
+    }
+}
+
+/// Checks that the set bits of `mask`, converted with `as u128`,
+/// are next to each other, so they can be read or written as one field.
+pub const fn check_contiguous(mask: u128) -> Result<(), BitError> {
+    // Filling in the bits below the field and adding one clears the field
+    // only if it has no gaps.
+    if (mask | mask.wrapping_sub(1)).wrapping_add(1) & mask == 0 {
+        Ok(())
+    } else {
+        Err(BitError::NonContiguousField)
+    }
+}
+
+/// Number of bits selected by `start..end` stepped by `step`.
+///
+/// Panics if `step` is zero.
+#[inline(always)]
+pub const fn stepped_count(start: usize, end: usize, step: usize) -> usize {
+    if start < end {
+        (end - start - 1) / step + 1
+    } else {
+        0
+    }
+}
+
+/// Reports a failed `debug-checks` validation in macro `name`.
+///
+/// A `const fn`, so the checks also work in const contexts,
+/// where a failed check becomes a compile error.
+#[cold]
+#[track_caller]
+pub const fn debug_check_failed(name: &str, err: BitError) -> ! {
+    let msg = err.describe(Message::new().push_str(name).push_str(": "));
+    panic!("{}", msg.as_str())
+}
+
+/// Fixed size buffer for building error messages in const fns,
+/// where `format!` isn't available.
+///
+/// Anything past the capacity is cut off.
+pub(crate) struct Message {
+    buf: [u8; 128],
+    len: usize,
+}
+
+impl Message {
+    pub(crate) const fn new() -> Self {
+        Message {
+            buf: [0; 128],
+            len: 0,
+        }
+    }
+
+    pub(crate) const fn push_str(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && self.len < self.buf.len() {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn push_usize(mut self, mut n: usize) -> Self {
+        let mut digits = [0; 20];
+        let mut count = 0;
+        loop {
+            digits[count] = b'0' + (n % 10) as u8;
+            count += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        while count > 0 && self.len < self.buf.len() {
+            count -= 1;
+            self.buf[self.len] = digits[count];
+            self.len += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            // Only happens if a multi-byte character was cut off.
+            Err(_) => "",
+        }
+    }
+}
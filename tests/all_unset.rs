@@ -0,0 +1,44 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::all_unset;
+
+#[test]
+fn all_unset_single_and_multiple_bits() {
+    let bitmap: u8 = 0b_0010_0010;
+
+    assert!(!all_unset!(bitmap, u8, 1));
+    assert!(all_unset!(bitmap, u8, 2));
+    assert!(!all_unset!(bitmap, [0, 5, 7]));
+    assert!(all_unset!(bitmap, u8, [0, 2, 7]));
+    assert!(!all_unset!(bitmap, rev 2));
+    assert!(all_unset!(bitmap, rev [0, 1, 3]));
+}
+
+#[test]
+fn all_unset_ranges() {
+    let bitmap: u64 = 0x_0100_0000_0000_0800;
+
+    assert!(!all_unset!(bitmap, u64, [8..16]));
+    assert!(all_unset!(bitmap, [12..56]));
+    assert!(!all_unset!(bitmap, [start = 50, count = 7]));
+    assert!(all_unset!(bitmap, rev [..=6]));
+    assert!(!all_unset!(bitmap, rev [start = 7, count = 1]));
+    assert!(all_unset!(bitmap, [1..; step = 4]));
+    assert!(all_unset!(bitmap, [4..4]));
+}
+
+#[test]
+fn all_unset_mixed_selectors_and_signed() {
+    let bitmap: i16 = i16::MIN;
+
+    assert!(!all_unset!(bitmap, [0, 1..3, rev 0]));
+    assert!(all_unset!(bitmap, i16, [0..15]));
+    assert!(all_unset!(0, i32, [..]));
+}
+
+const NONE: bool = all_unset!(0b_1000, u8, [0..3]);
+
+#[test]
+fn all_unset_in_const() {
+    assert_eq!(NONE, true);
+}
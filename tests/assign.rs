@@ -0,0 +1,84 @@
+use bit_fiddler::assign;
+
+#[test]
+fn assign_single_bit() {
+    let bitmap: u8 = 0b_0000_0100;
+
+    assert_eq!(assign!(bitmap, u8, 0, true), 0b_0000_0101);
+    assert_eq!(assign!(bitmap, u8, 2, false), 0);
+    assert_eq!(assign!(bitmap, 2, true), 0b_0000_0100);
+    assert_eq!(assign!(bitmap, rev 0, true), 0b_1000_0100);
+    assert_eq!(assign!(bitmap, u8, rev 5, false), 0);
+
+    let pos = 2;
+    assert_eq!(assign!(bitmap, pos, false), 0);
+    assert_eq!(assign!(bitmap, pos + 1, true), 0b_0000_1100);
+}
+
+#[test]
+fn assign_lists_and_ranges() {
+    let bitmap: u16 = 0b_0000_1111_0000_1111;
+
+    assert_eq!(assign!(bitmap, u16, [0, 4, 8], true), 0b_0000_1111_0001_1111);
+    assert_eq!(assign!(bitmap, [0, 4, 8], false), 0b_0000_1110_0000_1110);
+    assert_eq!(assign!(bitmap, [4..12], true), 0b_0000_1111_1111_1111);
+    assert_eq!(assign!(bitmap, u16, [start = 2, count = 8], false), 0b_0000_1100_0000_0011);
+    assert_eq!(assign!(bitmap, rev [..4], true), 0b_1111_1111_0000_1111);
+    assert_eq!(assign!(bitmap, [..; step = 2], false), 0b_0000_1010_0000_1010);
+    assert_eq!(assign!(bitmap, [4..4], true), bitmap);
+}
+
+#[test]
+fn assign_full_width_and_signed() {
+    assert_eq!(assign!(0, u128, [..], true), u128::MAX);
+    assert_eq!(assign!(0, i8, [..], true), -1);
+    assert_eq!(assign!(0, i8, rev 0, true), i8::MIN);
+
+    let bitmap: i64 = -1;
+    assert_eq!(assign!(bitmap, [..63], false), i64::MIN);
+}
+
+#[test]
+fn assign_in_place() {
+    let mut regs: [u32; 2] = [0, 0xff];
+    let idx = 1;
+
+    for (pos, flag) in [(0, true), (1, false), (2, true)] {
+        assign!(in regs[0], u32, pos, flag);
+    }
+    assert_eq!(regs[0], 0b_101);
+
+    assign!(in regs[idx], [4..8], false);
+    assign!(in regs[idx], rev 0, true);
+    assign!(in regs[idx], u32, rev [1..3], true);
+    assert_eq!(regs[1], 0xe000_000f);
+
+    let enabled = regs[0] == 0b_101;
+    assign!(in regs[0], [..], !enabled);
+    assert_eq!(regs[0], 0);
+}
+
+const CFG: u32 = assign!(0b_1111, u32, [2..6], false);
+
+#[test]
+fn assign_in_const() {
+    assert_eq!(CFG, 0b_0011);
+}
+
+#[test]
+fn assign_in_returns_previous() {
+    let mut cfg: u8 = 0b_1111_0000;
+
+    assert_eq!(assign!(in cfg, u8, [..4], true), 0b_1111_0000);
+    assert_eq!(assign!(in cfg, rev 0, false), 0xff);
+    assert_eq!(cfg, 0b_0111_1111);
+}
+
+#[test]
+fn assign_in_selector_reads_bitmap() {
+    let mut regs: [u8; 2] = [0, 3];
+    assign!(in regs[0], u8, regs[1], true);
+    assign!(in regs[0], [regs[1]..=regs[1] + 1], true);
+    assign!(in regs[0], rev regs[1], false);
+    assert_eq!(regs[0], 0b_0000_1000);
+}
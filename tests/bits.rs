@@ -0,0 +1,100 @@
+use bit_fiddler::{bits, put_bits, set, toggle, unset};
+
+#[test]
+fn bits_single_operations() {
+    let bitmap: u8 = 0b_1010_1010;
+
+    assert_eq!(bits!(bitmap, u8 { set [0..2] }), set!(bitmap, u8, [0..2]));
+    assert_eq!(bits!(bitmap, u8 { unset rev 0 }), unset!(bitmap, u8, rev 0));
+    assert_eq!(bits!(bitmap, u8 { toggle [..] }), toggle!(bitmap, u8, [..]));
+    assert_eq!(bits!(bitmap, u8 { put [2..6] = 0b_0101 }), put_bits!(bitmap, u8, [2..6] = 0b_0101));
+    assert_eq!(bits!(bitmap, u8 {}), bitmap);
+}
+
+#[test]
+fn bits_operations_apply_in_order() {
+    let bitmap: u16 = 0x_0ff0;
+
+    assert_eq!(bits!(bitmap, u16 { set [0..4], unset [2..6] }), 0x_0fc3);
+    assert_eq!(bits!(bitmap, u16 { unset [2..6], set [0..4] }), 0x_0fcf);
+    assert_eq!(bits!(bitmap, u16 { toggle [..8], toggle [4..] }), 0x_f0ff);
+    assert_eq!(bits!(bitmap, u16 { set 0, toggle 0 }), 0x_0ff0);
+    assert_eq!(bits!(bitmap, u16 { toggle 0, set 0 }), 0x_0ff1);
+    assert_eq!(bits!(bitmap, u16 { toggle [..8], put [4..12] = 0x_ab }), 0x_0abf);
+    assert_eq!(bits!(bitmap, u16 { put [4..12] = 0x_ab, toggle [..8] }), 0x_0a4f);
+}
+
+#[test]
+fn bits_selector_shapes() {
+    let bitmap: u32 = 0;
+    let pos = 3;
+
+    let res = bits!(bitmap, {
+        set [start = 8, count = 4],
+        set rev [..2],
+        toggle [16..24; step = 2],
+        set [pos, pos * 4, rev 2],
+        put rev [4..=7] = 0b_1001,
+    });
+    assert_eq!(res, 0b_1110_1001_0101_0101_0001_1111_0000_1000);
+}
+
+#[test]
+fn bits_in_place() {
+    struct Regs {
+        ctrl: [u32; 2],
+    }
+
+    let mut regs = Regs { ctrl: [0x_ffff_0000, 0] };
+    let mode = 0b_0110;
+
+    let prev = bits!(in regs.ctrl[0], u32 { set [0..3], unset 16, toggle rev 1, put [8..12] = mode });
+    assert_eq!(prev, 0x_ffff_0000);
+    assert_eq!(regs.ctrl[0], 0x_bffe_0607);
+
+    let prev = bits!(in regs.ctrl[1], { set rev 0, put [..4] = 0x_ff });
+    assert_eq!(prev, 0);
+    assert_eq!(regs.ctrl[1], 0x_8000_000f);
+}
+
+#[test]
+fn bits_place_evaluated_once() {
+    let mut regs = [0_u8; 2];
+    let mut evaluated = 0;
+
+    bits!(in regs[{ evaluated += 1; 1 }], u8 { set 0, set 1, toggle 7 });
+    assert_eq!(evaluated, 1);
+    assert_eq!(regs, [0, 0b_1000_0011]);
+}
+
+#[test]
+fn bits_signed_types() {
+    let bitmap: i8 = 0;
+
+    assert_eq!(bits!(bitmap, { set [..], unset rev 0 }), i8::MAX);
+    assert_eq!(bits!((-1), i16 { put rev [..4] = 0, toggle 0 }), 0x_0ffe);
+}
+
+const REG: u32 = bits!(0, u32 { set [4..8], toggle 0, put [12..16] = 3 });
+
+#[test]
+fn bits_in_const() {
+    assert_eq!(REG, 0x_30f1);
+}
+
+#[test]
+fn bits_put_value_reads_bitmap() {
+    let mut reg: u32 = 0x_50;
+
+    bits!(in reg, u32 { put [0..4] = reg >> 4, set reg >> 4 });
+    assert_eq!(reg, 0x_75);
+
+    bits!(in reg, { put [8..12] = reg & 0xf, unset [..reg >> 5] });
+    assert_eq!(reg, 0x_570);
+
+    let mut regs = [0_u8; 2];
+    let mut evaluated = 0;
+    bits!(in regs[{ evaluated += 1; 1 }], { set 0, put [4..8] = 3 });
+    assert_eq!(evaluated, 1);
+    assert_eq!(regs, [0, 0x_31]);
+}
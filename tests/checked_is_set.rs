@@ -0,0 +1,32 @@
+use bit_fiddler::{checked_is_set, BitError};
+
+#[test]
+fn checked_is_set_valid() {
+    let bitmap: u8 = 0b_1000_0110;
+
+    assert_eq!(checked_is_set!(bitmap, u8, 7), Ok(true));
+    assert_eq!(checked_is_set!(bitmap, u8, rev 1), Ok(false));
+    assert_eq!(checked_is_set!(bitmap, u8, [1, 2]), Ok(true));
+    assert_eq!(checked_is_set!(bitmap, u8, [1..4]), Ok(false));
+    assert_eq!(checked_is_set!(bitmap, rev [..1]), Ok(true));
+    assert_eq!(checked_is_set!(bitmap, [start = 1, count = 2]), Ok(true));
+    assert_eq!(checked_is_set!(bitmap, [3..3]), Ok(true));
+}
+
+#[test]
+fn checked_is_set_invalid() {
+    let bitmap: u64 = u64::MAX;
+
+    assert_eq!(
+        checked_is_set!(bitmap, u64, 64),
+        Err(BitError::PositionOutOfRange { pos: 64, max_bits: 64 })
+    );
+    assert_eq!(
+        checked_is_set!(bitmap, rev [10..5]),
+        Err(BitError::StartAfterEnd { start: 10, end: 5 })
+    );
+    assert_eq!(
+        checked_is_set!(bitmap, rev [63, 64]),
+        Err(BitError::RevUnderflow { pos: 64, max_bits: 64 })
+    );
+}
@@ -0,0 +1,196 @@
+use bit_fiddler::{checked_mask, mask, BitError};
+
+#[test]
+fn checked_mask_valid() {
+    assert_eq!(checked_mask!(3, u8), Ok(mask!(3, u8)));
+    assert_eq!(checked_mask!(rev 3, u8), Ok(mask!(rev 3, u8)));
+    assert_eq!(checked_mask!([0, 7], u8), Ok(mask!([0, 7], u8)));
+    assert_eq!(checked_mask!(rev [0, 7], u8), Ok(mask!(rev [0, 7], u8)));
+    assert_eq!(checked_mask!([2..6], u8), Ok(mask!([2..6], u8)));
+    assert_eq!(checked_mask!([..5], u8), Ok(mask!([..5], u8)));
+    assert_eq!(checked_mask!([3..], u8), Ok(mask!([3..], u8)));
+    assert_eq!(checked_mask!([..], u8), Ok(mask!([..], u8)));
+    assert_eq!(checked_mask!(rev [2..6], u8), Ok(mask!(rev [2..6], u8)));
+    assert_eq!(checked_mask!(rev [..5], u8), Ok(mask!(rev [..5], u8)));
+    assert_eq!(checked_mask!(rev [3..], u8), Ok(mask!(rev [3..], u8)));
+    assert_eq!(checked_mask!([start = 0, count = 8], u8), Ok(u8::MAX));
+    assert_eq!(checked_mask!(rev [start = 1, count = 2], u8), Ok(0b_0110_0000));
+}
+
+#[test]
+fn checked_mask_empty_ranges() {
+    assert_eq!(checked_mask!([0..0], u8), Ok(0));
+    assert_eq!(checked_mask!([..0], u8), Ok(0));
+    assert_eq!(checked_mask!([8..], u8), Ok(0));
+    assert_eq!(checked_mask!(rev [8..8], u8), Ok(0));
+    assert_eq!(checked_mask!([start = 8, count = 0], u8), Ok(0));
+}
+
+#[test]
+fn checked_mask_invalid() {
+    assert_eq!(
+        checked_mask!(8, u8),
+        Err(BitError::PositionOutOfRange { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!(rev 8, u8),
+        Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([1, 64, 2], u32),
+        Err(BitError::PositionOutOfRange { pos: 64, max_bits: 32 })
+    );
+    assert_eq!(
+        checked_mask!(rev [1, 32], u32),
+        Err(BitError::RevUnderflow { pos: 32, max_bits: 32 })
+    );
+    assert_eq!(
+        checked_mask!([5..3], u8),
+        Err(BitError::StartAfterEnd { start: 5, end: 3 })
+    );
+    assert_eq!(
+        checked_mask!([9..], u8),
+        Err(BitError::StartAfterEnd { start: 9, end: 8 })
+    );
+    assert_eq!(
+        checked_mask!(rev [..9], u8),
+        Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([start = 6, count = 3], u8),
+        Err(BitError::CountOverflow { start: 6, count: 3, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!(rev [start = 1, count = usize::MAX], u8),
+        Err(BitError::CountOverflow { start: 1, count: usize::MAX, max_bits: 8 })
+    );
+}
+
+#[test]
+fn checked_mask_runtime_positions() {
+    let positions: [i32; 3] = [3, -1, 100];
+
+    assert_eq!(checked_mask!(positions[0], u16), Ok(0b_1000));
+    assert!(checked_mask!(positions[1], u16).is_err());
+    assert!(checked_mask!(rev positions[2], u16).is_err());
+    assert_eq!(checked_mask!([positions[0]..positions[0] + 2], u16), Ok(0b_0001_1000));
+    assert_eq!(
+        checked_mask!([start = positions[0], count = usize::MAX], u16),
+        Err(BitError::CountOverflow { start: 3, count: usize::MAX, max_bits: 16 })
+    );
+}
+
+#[test]
+fn checked_mask_wide_positions() {
+    let wide: u128 = 1 << 64;
+    let negative: i64 = -1;
+
+    assert_eq!(
+        checked_mask!(wide, u8),
+        Err(BitError::PositionOutOfRange { pos: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!(rev [wide + 1], u8),
+        Err(BitError::RevUnderflow { pos: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([0..wide], u8),
+        Err(BitError::EndOutOfRange { end: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([negative..4], u8),
+        Err(BitError::StartAfterEnd { start: usize::MAX, end: 4 })
+    );
+    assert!(checked_mask!([start = 0, count = wide], u8).is_err());
+    assert_eq!(checked_mask!([0..8; step = wide], u8), Ok(0b_0000_0001));
+}
+
+#[test]
+fn bit_error_display() {
+    let err = BitError::PositionOutOfRange { pos: 8, max_bits: 8 };
+    assert_eq!(err.to_string(), "bit position 8 is out of range for a type of 8 bits");
+
+    let err = BitError::StartAfterEnd { start: 5, end: 3 };
+    assert_eq!(err.to_string(), "range start 5 is greater than range end 3");
+}
+
+#[test]
+fn checked_mask_mixed_selectors() {
+    assert_eq!(checked_mask!([0, 4..8, rev 0], u8), Ok(0b_1111_0001));
+    assert_eq!(checked_mask!(rev [0, start = 2, count = 2], u8), Ok(0b_1011_0000));
+    assert_eq!(
+        checked_mask!([0, 4..9, rev 8], u8),
+        Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([0, 4..8, rev 8], u8),
+        Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+    );
+}
+
+#[test]
+fn checked_mask_empty_and_full_ranges() {
+    assert_eq!(checked_mask!([..0], u8), Ok(0));
+    assert_eq!(checked_mask!(rev [8..], u8), Ok(0));
+    assert_eq!(checked_mask!([start = 8, count = 0], u8), Ok(0));
+    assert_eq!(checked_mask!([0..8], i8), Ok(-1));
+    assert_eq!(checked_mask!(rev [..64], u64), Ok(u64::MAX));
+}
+
+#[test]
+fn checked_mask_stepped_ranges() {
+    assert_eq!(checked_mask!([0..8; step = 2], u8), Ok(0b_0101_0101));
+    assert_eq!(checked_mask!(rev [start = 0, count = 2, step = 7], u8), Ok(0b_1000_0001));
+    assert_eq!(checked_mask!([0..8; step = 0], u8), Err(BitError::ZeroStep));
+    assert_eq!(
+        checked_mask!([0..9; step = 2], u8),
+        Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([start = 1, count = 5, step = 2], u8),
+        Err(BitError::CountOverflow { start: 1, count: 5, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([start = 0, count = 2, step = usize::MAX], u8),
+        Err(BitError::CountOverflow { start: 0, count: 2, max_bits: 8 })
+    );
+    assert_eq!(checked_mask!([start = 0, count = 3, step = 0], u8), Err(BitError::ZeroStep));
+}
+
+#[test]
+fn checked_mask_inclusive_ranges() {
+    assert_eq!(checked_mask!([4..=7], u8), Ok(0b_1111_0000));
+    assert_eq!(checked_mask!([0..=7], u8), Ok(u8::MAX));
+    assert_eq!(checked_mask!(rev [..=0], u8), Ok(0b_1000_0000));
+    assert_eq!(
+        checked_mask!([0..=8], u8),
+        Err(BitError::EndOutOfRange { end: 8, max_bits: 8 })
+    );
+    assert_eq!(checked_mask!([4..=3], u8), Ok(0));
+    assert_eq!(
+        checked_mask!([5..=3], u8),
+        Err(BitError::StartAfterEnd { start: 5, end: 3 })
+    );
+
+    let n: u8 = 255;
+    assert_eq!(
+        checked_mask!([0..=n], u8),
+        Err(BitError::EndOutOfRange { end: 255, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!(rev [..=usize::MAX], u8),
+        Err(BitError::EndOutOfRange { end: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_mask!([..=usize::MAX; step = 2], u8),
+        Err(BitError::EndOutOfRange { end: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(checked_mask!([1..=7; step = 3], u8), Ok(0b_1001_0010));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bit_error_is_std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(BitError::ZeroStep);
+    assert_eq!(err.to_string(), "range step must be greater than zero");
+}
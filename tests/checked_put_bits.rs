@@ -0,0 +1,149 @@
+use bit_fiddler::{checked_put_bits, put_bits, BitError};
+
+#[test]
+fn checked_put_bits_valid() {
+    let reg: u32 = 0b_1111_0000_1111;
+
+    assert_eq!(checked_put_bits!(reg, u32, [4..8] = 0b_1010), Ok(put_bits!(reg, u32, [4..8] = 0b_1010)));
+    assert_eq!(checked_put_bits!(reg, rev [..4] = 0b_1001), Ok(put_bits!(reg, rev [..4] = 0b_1001)));
+    assert_eq!(checked_put_bits!(reg, u32, [..] = u32::MAX), Ok(u32::MAX));
+    assert_eq!(checked_put_bits!(reg, u32, [4..4] = 0), Ok(reg));
+    assert_eq!(checked_put_bits!((-1), i8, [4..] = 0b_0111), Ok(0b_0111_1111));
+    assert_eq!(checked_put_bits!(0, i8, [..] = -1), Ok(-1));
+}
+
+#[test]
+fn checked_put_bits_value_too_wide() {
+    let reg: u8 = 0;
+
+    assert_eq!(
+        checked_put_bits!(reg, u8, [2..4] = 0b_100),
+        Err(BitError::ValueTooWide { field_bits: 2 })
+    );
+    assert_eq!(
+        checked_put_bits!(reg, rev [start = 0, count = 3] = 8),
+        Err(BitError::ValueTooWide { field_bits: 3 })
+    );
+    assert_eq!(
+        checked_put_bits!(reg, u8, [4..4] = 1),
+        Err(BitError::ValueTooWide { field_bits: 0 })
+    );
+    assert_eq!(
+        checked_put_bits!(0, i8, [..4] = -1),
+        Err(BitError::ValueTooWide { field_bits: 4 })
+    );
+}
+
+#[test]
+fn checked_put_bits_value_wider_than_type() {
+    let wide: u32 = 0x_100;
+    let negative: i32 = -1;
+    let mut reg: u8 = 0;
+
+    assert_eq!(
+        checked_put_bits!(0u8, u8, [0..4] = wide),
+        Err(BitError::ValueTooWide { field_bits: 4 })
+    );
+    assert_eq!(
+        checked_put_bits!(0u8, u8, [..] = wide),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(in reg, u8, rev [..4] = negative),
+        Err(BitError::ValueTooWide { field_bits: 4 })
+    );
+    assert_eq!(reg, 0);
+    assert_eq!(
+        checked_put_bits!(0u8, u8, [0, 4] = wide),
+        Err(BitError::NonContiguousField)
+    );
+
+    assert_eq!(
+        checked_put_bits!(0, u8, [0..8] = 300u16),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(in reg, u8, [..] = 0x1ffu32),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(0, u8, rev [..4] = -1_i16),
+        Err(BitError::ValueTooWide { field_bits: 4 })
+    );
+    assert_eq!(
+        checked_put_bits!(0, u8, [0..8] = -1),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(0, i8, [0..8] = 200),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(checked_put_bits!(0, u8, [..] = 0xffu32), Ok(0xff));
+    assert_eq!(checked_put_bits!(0, u64, [..] = 0x_ffff_ffff_ffff_u64), Ok(0x_ffff_ffff_ffff));
+    assert_eq!(checked_put_bits!(0, u128, [..] = u128::MAX), Ok(u128::MAX));
+    assert_eq!(checked_put_bits!(in reg, u8, [4..] = wide >> 5), Ok(0));
+    assert_eq!(reg, 0b_1000_0000);
+    assert_eq!(checked_put_bits!(0, i8, [..] = negative), Ok(-1));
+}
+
+#[test]
+fn checked_put_bits_invalid_positions() {
+    assert_eq!(
+        checked_put_bits!(0, u8, [4..9] = 0),
+        Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(0, u8, rev [5..3] = 0),
+        Err(BitError::StartAfterEnd { start: 5, end: 3 })
+    );
+}
+
+#[test]
+fn checked_put_bits_non_contiguous() {
+    let mut reg: u8 = 0;
+
+    assert_eq!(checked_put_bits!(reg, u8, [2, 3, 4] = 0b_101), Ok(0b_0001_0100));
+    assert_eq!(
+        checked_put_bits!(reg, u8, [0..8; step = 2] = 0b_1111),
+        Err(BitError::NonContiguousField)
+    );
+    assert_eq!(
+        checked_put_bits!(in reg, u8, [0, 4] = 3),
+        Err(BitError::NonContiguousField)
+    );
+    assert_eq!(
+        checked_put_bits!(in reg, rev [0, 7] = 0),
+        Err(BitError::NonContiguousField)
+    );
+    assert_eq!(reg, 0);
+}
+
+#[test]
+fn checked_put_bits_in_place() {
+    let mut reg: u16 = 0;
+
+    assert_eq!(checked_put_bits!(in reg, u16, [8..] = 0x_ab), Ok(0));
+    assert_eq!(reg, 0x_ab00);
+
+    assert_eq!(
+        checked_put_bits!(in reg, [..8] = 0x_100),
+        Err(BitError::ValueTooWide { field_bits: 8 })
+    );
+    assert_eq!(
+        checked_put_bits!(in reg, u16, rev [..17] = 0),
+        Err(BitError::EndOutOfRange { end: 17, max_bits: 16 })
+    );
+    assert_eq!(reg, 0x_ab00);
+
+    assert_eq!(checked_put_bits!(in reg, rev [8..12] = 0x_c), Ok(0x_ab00));
+    assert_eq!(reg, 0x_abc0);
+}
+
+#[test]
+fn value_too_wide_display() {
+    let err = BitError::ValueTooWide { field_bits: 4 };
+    assert_eq!(err.to_string(), "value doesn't fit in a field of 4 bits");
+
+    let err = BitError::NonContiguousField;
+    assert_eq!(err.to_string(), "selected bits don't form a contiguous field");
+}
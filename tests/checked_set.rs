@@ -0,0 +1,52 @@
+use bit_fiddler::{checked_set, BitError};
+
+#[test]
+fn checked_set_valid() {
+    let mut bitmap: u8 = 0;
+
+    assert_eq!(checked_set!(bitmap, u8, 7), Ok(0b_1000_0000));
+    assert_eq!(checked_set!(bitmap, u8, rev [0, 1]), Ok(0b_1100_0000));
+    assert_eq!(checked_set!(0, u8, [2..4]), Ok(0b_0000_1100));
+    assert_eq!(checked_set!(bitmap, rev [start = 0, count = 2]), Ok(0b_1100_0000));
+
+    assert_eq!(checked_set!(in bitmap, u8, [1..3]), Ok(0));
+    assert_eq!(bitmap, 0b_0000_0110);
+
+    assert_eq!(checked_set!(in bitmap, rev 0), Ok(0b_0000_0110));
+    assert_eq!(bitmap, 0b_1000_0110);
+}
+
+#[test]
+fn checked_set_invalid() {
+    let mut bitmap: u8 = 0b_0000_0001;
+
+    assert_eq!(
+        checked_set!(bitmap, u8, 8),
+        Err(BitError::PositionOutOfRange { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_set!(in bitmap, u8, [4..2]),
+        Err(BitError::StartAfterEnd { start: 4, end: 2 })
+    );
+    assert_eq!(
+        checked_set!(in bitmap, rev [0, 8]),
+        Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(bitmap, 0b_0000_0001);
+
+    let mut regs: [u32; 2] = [0; 2];
+    assert_eq!(
+        checked_set!(in regs[1], [start = 30, count = 3]),
+        Err(BitError::CountOverflow { start: 30, count: 3, max_bits: 32 })
+    );
+    assert_eq!(regs, [0; 2]);
+}
+
+#[test]
+fn checked_set_in_position_reads_bitmap() {
+    let mut reg: u32 = 0b_0010;
+    assert_eq!(checked_set!(in reg, u32, (reg & 7)), Ok(0b_0010));
+    assert_eq!(checked_set!(in reg, [reg..8]), Ok(0b_0110));
+    assert_eq!(checked_set!(in reg, reg), Err(BitError::PositionOutOfRange { pos: 0b_1100_0110, max_bits: 32 }));
+    assert_eq!(reg, 0b_1100_0110);
+}
@@ -0,0 +1,36 @@
+use bit_fiddler::{checked_toggle, BitError};
+
+#[test]
+fn checked_toggle_valid() {
+    let mut bitmap: u8 = 0b_0000_1111;
+
+    assert_eq!(checked_toggle!(bitmap, u8, 7), Ok(0b_1000_1111));
+    assert_eq!(checked_toggle!(bitmap, u8, rev [0, 1]), Ok(0b_1100_1111));
+    assert_eq!(checked_toggle!(0b_0101, u8, [..4]), Ok(0b_1010));
+    assert_eq!(checked_toggle!(bitmap, [start = 2, count = 4]), Ok(0b_0011_0011));
+
+    assert_eq!(checked_toggle!(in bitmap, u8, [..]), Ok(0b_0000_1111));
+    assert_eq!(bitmap, 0b_1111_0000);
+
+    assert_eq!(checked_toggle!(in bitmap, rev 0), Ok(0b_1111_0000));
+    assert_eq!(bitmap, 0b_0111_0000);
+}
+
+#[test]
+fn checked_toggle_invalid() {
+    let mut bitmap: u16 = 0b_0000_1111;
+
+    assert_eq!(
+        checked_toggle!(bitmap, u16, rev [3..17]),
+        Err(BitError::EndOutOfRange { end: 17, max_bits: 16 })
+    );
+    assert_eq!(
+        checked_toggle!(in bitmap, u16, rev [start = 10, count = 7]),
+        Err(BitError::CountOverflow { start: 10, count: 7, max_bits: 16 })
+    );
+    assert_eq!(
+        checked_toggle!(in bitmap, 16),
+        Err(BitError::PositionOutOfRange { pos: 16, max_bits: 16 })
+    );
+    assert_eq!(bitmap, 0b_0000_1111);
+}
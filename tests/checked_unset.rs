@@ -0,0 +1,36 @@
+use bit_fiddler::{checked_unset, BitError};
+
+#[test]
+fn checked_unset_valid() {
+    let mut bitmap: u8 = 0b_1111_1111;
+
+    assert_eq!(checked_unset!(bitmap, u8, 7), Ok(0b_0111_1111));
+    assert_eq!(checked_unset!(bitmap, u8, rev [0, 1]), Ok(0b_0011_1111));
+    assert_eq!(checked_unset!(0b_1111, u8, [2..4]), Ok(0b_0011));
+    assert_eq!(checked_unset!(bitmap, rev [start = 0, count = 2]), Ok(0b_0011_1111));
+
+    assert_eq!(checked_unset!(in bitmap, u8, [1..3]), Ok(0b_1111_1111));
+    assert_eq!(bitmap, 0b_1111_1001);
+
+    assert_eq!(checked_unset!(in bitmap, rev 0), Ok(0b_1111_1001));
+    assert_eq!(bitmap, 0b_0111_1001);
+}
+
+#[test]
+fn checked_unset_invalid() {
+    let mut bitmap: u8 = 0b_1111_1111;
+
+    assert_eq!(
+        checked_unset!(bitmap, u8, [..9]),
+        Err(BitError::EndOutOfRange { end: 9, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_unset!(in bitmap, u8, [1, 2, 10]),
+        Err(BitError::PositionOutOfRange { pos: 10, max_bits: 8 })
+    );
+    assert_eq!(
+        checked_unset!(in bitmap, rev 8),
+        Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(bitmap, 0b_1111_1111);
+}
@@ -0,0 +1,94 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::{is_set, mask, max_bits, set, toggle, unset};
+
+const IRQ_MASK: u32 = mask!([4..8], u32) | set!(0, u32, [0, 2]);
+
+const MASKS: [u8; 16] = [
+    mask!(3, u8),
+    mask!(rev 3, u8),
+    mask!([1, 3], u8),
+    mask!(rev [1, 3], u8),
+    mask!([..], u8),
+    mask!(rev [..], u8),
+    mask!([2..5], u8),
+    mask!(rev [2..5], u8),
+    mask!([..3], u8),
+    mask!(rev [5..], u8),
+    mask!([start = 1, count = 2], u8),
+    mask!(rev [start = 1, count = 2], u8),
+    mask!([0, 4..=5, rev 0], u8),
+    mask!([..; step = 3], u8),
+    mask!(rev [start = 0, count = 2, step = 2], u8),
+    mask!([8..], u8),
+];
+
+const BITS: [usize; 3] = [max_bits!(type = u16), max_bits!(0_i64), max_bits!(IRQ_MASK)];
+
+const SET: u16 = set!(0, u16, rev [..4]);
+const UNSET: u16 = unset!(0xffff, u16, [start = 0, count = 8]);
+const TOGGLE: i8 = toggle!(0, i8, [0..8; step = 2]);
+const IS_SET: bool = is_set!(IRQ_MASK, u32, [4..=7]);
+
+const fn enable(bitmap: u32, channel: u32) -> u32 {
+    let bitmap = set!(bitmap, channel * 2);
+    let bitmap = unset!(bitmap, [16..]);
+    toggle!(bitmap, rev 0)
+}
+
+const fn is_ready(bitmap: u8) -> bool {
+    is_set!(bitmap, [0, 2..4]) && !is_set!(bitmap, rev [start = 0, count = 1])
+}
+
+static STATIC_MASK: u64 = mask!(rev [0..32], u64);
+
+#[test]
+fn const_masks() {
+    assert_eq!(IRQ_MASK, 0b_1111_0101);
+    assert_eq!(
+        MASKS,
+        [
+            0b_0000_1000,
+            0b_0001_0000,
+            0b_0000_1010,
+            0b_0101_0000,
+            0b_1111_1111,
+            0b_1111_1111,
+            0b_0001_1100,
+            0b_0011_1000,
+            0b_0000_0111,
+            0b_0000_0111,
+            0b_0000_0110,
+            0b_0110_0000,
+            0b_1011_0001,
+            0b_0100_1001,
+            0b_1010_0000,
+            0,
+        ]
+    );
+    assert_eq!(STATIC_MASK, 0x_ffff_ffff_0000_0000);
+}
+
+#[test]
+fn const_max_bits() {
+    assert_eq!(BITS, [16, 64, 32]);
+}
+
+#[test]
+fn const_value_forms() {
+    assert_eq!(SET, 0b_1111 << 12);
+    assert_eq!(UNSET, 0x_ff00);
+    assert_eq!(TOGGLE, 0b_0101_0101);
+    assert_eq!(IS_SET, true);
+}
+
+#[test]
+fn const_fns() {
+    const ENABLED: u32 = enable(0x_ffff_0000, 3);
+    assert_eq!(ENABLED, (1 << 31) | 0b_0100_0000);
+    assert_eq!(enable(0, 1), (1 << 31) | 0b_0100);
+
+    const READY: bool = is_ready(0b_0000_1101);
+    assert_eq!(READY, true);
+    assert!(!is_ready(0b_1000_1101));
+}
@@ -0,0 +1,51 @@
+use bit_fiddler::count_set;
+
+#[test]
+fn count_set_whole_bitmap() {
+    let bitmap: u64 = 0x_f0f0;
+
+    assert_eq!(count_set!(bitmap), 8);
+    assert_eq!(count_set!(bitmap, u64, [..]), 8);
+    assert_eq!(count_set!(0, u8, [..]), 0);
+
+    let bitmap: i32 = -1;
+    assert_eq!(count_set!(bitmap), 32);
+}
+
+#[test]
+fn count_set_single_and_multiple_bits() {
+    let bitmap: u64 = 0b_0010_0010;
+
+    assert_eq!(count_set!(bitmap, u64, 1), 1);
+    assert_eq!(count_set!(bitmap, u64, 2), 0);
+    assert_eq!(count_set!(bitmap, u64, [1, 5, 9]), 2);
+    assert_eq!(count_set!(bitmap, u64, rev [58, 62, 63]), 2);
+    assert_eq!(count_set!(bitmap, rev 58), 1);
+}
+
+#[test]
+fn count_set_ranges() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert_eq!(count_set!(bitmap, u64, [8..16]), 4);
+    assert_eq!(count_set!(bitmap, u64, rev [0..4]), 4);
+    assert_eq!(count_set!(bitmap, [start = 0, count = 12]), 8);
+    assert_eq!(count_set!(bitmap, rev [..=7]), 8);
+    assert_eq!(count_set!(bitmap, [0..; step = 2]), 8);
+    assert_eq!(count_set!(bitmap, [4..4]), 0);
+}
+
+#[test]
+fn count_set_mixed_selectors() {
+    let bitmap: u16 = 0b_1000_0000_0000_0111;
+
+    assert_eq!(count_set!(bitmap, [0, 1..3, rev 0]), 4);
+    assert_eq!(count_set!(bitmap, u16, [3..15, rev 0]), 1);
+}
+
+const COUNT: u32 = count_set!(0b_1011, u8, [..3]);
+
+#[test]
+fn count_set_in_const() {
+    assert_eq!(COUNT, 2);
+}
@@ -0,0 +1,42 @@
+use bit_fiddler::count_unset;
+
+#[test]
+fn count_unset_whole_bitmap() {
+    let bitmap: u64 = 0x_f0f0;
+
+    assert_eq!(count_unset!(bitmap), 56);
+    assert_eq!(count_unset!(bitmap, u64, [..]), 56);
+    assert_eq!(count_unset!(0, u8, [..]), 8);
+
+    let bitmap: i32 = -1;
+    assert_eq!(count_unset!(bitmap), 0);
+}
+
+#[test]
+fn count_unset_single_and_multiple_bits() {
+    let bitmap: u64 = 0b_0010_0010;
+
+    assert_eq!(count_unset!(bitmap, u64, 1), 0);
+    assert_eq!(count_unset!(bitmap, u64, 2), 1);
+    assert_eq!(count_unset!(bitmap, u64, [1, 5, 9]), 1);
+    assert_eq!(count_unset!(bitmap, rev [58, 62, 63]), 1);
+}
+
+#[test]
+fn count_unset_ranges() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert_eq!(count_unset!(bitmap, u64, [8..16]), 4);
+    assert_eq!(count_unset!(bitmap, u64, rev [0..4]), 0);
+    assert_eq!(count_unset!(bitmap, [start = 0, count = 12]), 4);
+    assert_eq!(count_unset!(bitmap, rev [8..=63]), 48);
+    assert_eq!(count_unset!(bitmap, [4..4]), 0);
+}
+
+#[test]
+fn count_unset_signed() {
+    let bitmap: i8 = 0b_0000_1111;
+
+    assert_eq!(count_unset!(bitmap, [4..]), 4);
+    assert_eq!(count_unset!(bitmap, i8, rev [..1]), 1);
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "debug-checks")]
+
+use bit_fiddler::{all_unset, bits, get_bits, is_set, mask, put_bits, set, toggle, unset};
+
+#[test]
+fn valid_args_pass() {
+    let mut bitmap: u8 = 0;
+
+    set!(in bitmap, u8, [..8]);
+    unset!(in bitmap, u8, rev [start = 0, count = 8]);
+    toggle!(in bitmap, [0, 7]);
+    assert!(is_set!(bitmap, u8, rev 0));
+    assert_eq!(mask!([0..8], u8), u8::MAX);
+}
+
+#[test]
+#[should_panic(expected = "set!: bit position 8 is out of range for a type of 8 bits")]
+fn set_position_out_of_range() {
+    let pos = 8;
+    let _ = set!(0, u8, pos);
+}
+
+#[test]
+#[should_panic(expected = "unset!: rev bit position 16 is out of range for a type of 16 bits")]
+fn unset_rev_underflow() {
+    let mut bitmap: u16 = 0;
+    unset!(in bitmap, rev [0, 16]);
+}
+
+#[test]
+#[should_panic(expected = "toggle!: range start 5 is greater than range end 3")]
+fn toggle_start_after_end() {
+    let _ = toggle!(0, u8, [5..3]);
+}
+
+#[test]
+#[should_panic(expected = "is_set!: range end 9 is out of range for a type of 8 bits")]
+fn is_set_end_out_of_range() {
+    let _ = is_set!(0, u8, rev [..9]);
+}
+
+#[test]
+#[should_panic(expected = "mask!: 3 bits starting at 30 don't fit in a type of 32 bits")]
+fn mask_count_overflow() {
+    let _ = mask!([start = 30, count = 3], u32);
+}
+
+#[test]
+#[should_panic(expected = "set!: range end 9 is out of range for a type of 8 bits")]
+fn set_mixed_selectors_checked() {
+    let _ = set!(0, u8, [0, 4..9, rev 0]);
+}
+
+#[test]
+fn empty_and_full_ranges_pass() {
+    let mut bitmap: u8 = 0;
+
+    set!(in bitmap, [8..]);
+    unset!(in bitmap, rev [..0]);
+    toggle!(in bitmap, u8, [start = 8, count = 0]);
+    assert!(is_set!(bitmap, [0..0]));
+    assert_eq!(mask!(rev [0..8], u8), u8::MAX);
+}
+
+#[test]
+#[should_panic(expected = "mask!: range step must be greater than zero")]
+fn mask_zero_step() {
+    let step = 0;
+    let _ = mask!([0..8; step = step], u8);
+}
+
+#[test]
+#[should_panic(expected = "set!: 5 bits starting at 0 don't fit in a type of 8 bits")]
+fn set_stepped_count_overflow() {
+    let _ = set!(0, u8, [start = 0, count = 5, step = 2]);
+}
+
+#[test]
+#[should_panic(expected = "bits!: bit position 32 is out of range for a type of 32 bits")]
+fn bits_position_out_of_range() {
+    let mut reg: u32 = 0;
+    let pos = 32;
+    bits!(in reg, u32 { set 0, toggle pos });
+}
+
+#[test]
+#[should_panic(expected = "all_unset!: range end 9 is out of range for a type of 8 bits")]
+fn all_unset_range_out_of_range() {
+    let bitmap: u8 = 0;
+    let _ = all_unset!(bitmap, [4..9]);
+}
+
+#[test]
+#[should_panic(expected = "mask!: range end 255 is out of range for a type of 8 bits")]
+fn mask_inclusive_end_out_of_range() {
+    let end: u8 = 255;
+    let _ = mask!([0..=end], u8);
+}
+
+#[test]
+fn contiguous_fields_pass() {
+    let reg: u8 = 0b_0111_0000;
+
+    assert_eq!(get_bits!(reg, u8, [4, 5, 6]), 0b_0111);
+    assert_eq!(get_bits!(reg, [start = 4, count = 3, step = 1]), 0b_0111);
+    assert_eq!(get_bits!((-1), i8, rev [0]), 1);
+    assert_eq!(put_bits!(reg, u8, [0, 1, 2] = 0b_101), 0b_0111_0101);
+}
+
+#[test]
+#[should_panic(expected = "get_bits!: selected bits don't form a contiguous field")]
+fn get_bits_non_contiguous() {
+    let _ = get_bits!(0b_1111, u8, [0..8; step = 2]);
+}
+
+#[test]
+#[should_panic(expected = "put_bits!: selected bits don't form a contiguous field")]
+fn put_bits_non_contiguous() {
+    let mut reg: u8 = 0;
+    put_bits!(in reg, u8, [0, 4] = 3);
+}
+
+#[test]
+#[should_panic(expected = "bits!: selected bits don't form a contiguous field")]
+fn bits_put_non_contiguous() {
+    let _ = bits!(0, u8 { set 1, put rev [0..8; step = 2] = 0b_1111 });
+}
@@ -0,0 +1,42 @@
+use bit_fiddler::first_set;
+
+#[test]
+fn first_set_whole_bitmap() {
+    let bitmap: u32 = 0b_1010_0000;
+
+    assert_eq!(first_set!(bitmap), Some(5));
+    assert_eq!(first_set!(bitmap, u32, [..]), Some(5));
+    assert_eq!(first_set!(0, u8, [..]), None);
+
+    let bitmap: i16 = i16::MIN;
+    assert_eq!(first_set!(bitmap), Some(15));
+}
+
+#[test]
+fn first_set_selectors() {
+    let bitmap: u64 = 0x_8000_0000_0001_0f00;
+
+    assert_eq!(first_set!(bitmap, u64, 8), Some(8));
+    assert_eq!(first_set!(bitmap, u64, 7), None);
+    assert_eq!(first_set!(bitmap, [0..8, 16, 40..]), Some(16));
+    assert_eq!(first_set!(bitmap, [start = 10, count = 8]), Some(10));
+    assert_eq!(first_set!(bitmap, [1..; step = 2]), Some(9));
+    assert_eq!(first_set!(bitmap, [4..4]), None);
+}
+
+#[test]
+fn first_set_rev_selectors() {
+    let bitmap: u64 = 0x_8000_0000_0001_0f00;
+
+    assert_eq!(first_set!(bitmap, rev [..]), Some(0));
+    assert_eq!(first_set!(bitmap, u64, rev [1..]), Some(47));
+    assert_eq!(first_set!(bitmap, rev 0), Some(0));
+    assert_eq!(first_set!(bitmap, rev [1..47]), None);
+}
+
+const FIRST: Option<u32> = first_set!(0b_0110, u8, [..]);
+
+#[test]
+fn first_set_in_const() {
+    assert_eq!(FIRST, Some(1));
+}
@@ -0,0 +1,41 @@
+use bit_fiddler::first_unset;
+
+#[test]
+fn first_unset_whole_bitmap() {
+    let bitmap: u32 = 0b_0101_1111;
+
+    assert_eq!(first_unset!(bitmap), Some(5));
+    assert_eq!(first_unset!(bitmap, u32, [..]), Some(5));
+    assert_eq!(first_unset!(0xff, u8, [..]), None);
+
+    let bitmap: i16 = i16::MAX;
+    assert_eq!(first_unset!(bitmap), Some(15));
+}
+
+#[test]
+fn first_unset_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert_eq!(first_unset!(bitmap, u16, 4), Some(4));
+    assert_eq!(first_unset!(bitmap, u16, 5), None);
+    assert_eq!(first_unset!(bitmap, [0..4, 5..8, 10]), Some(10));
+    assert_eq!(first_unset!(bitmap, [start = 5, count = 8]), Some(8));
+    assert_eq!(first_unset!(bitmap, [1..; step = 4]), Some(9));
+    assert_eq!(first_unset!(bitmap, [12..]), None);
+}
+
+#[test]
+fn first_unset_rev_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert_eq!(first_unset!(bitmap, rev [..]), Some(4));
+    assert_eq!(first_unset!(bitmap, u16, rev [9..]), Some(11));
+    assert_eq!(first_unset!(bitmap, rev [..4]), None);
+}
+
+const FIRST: Option<u32> = first_unset!(0b_1001, u8, [..]);
+
+#[test]
+fn first_unset_in_const() {
+    assert_eq!(FIRST, Some(1));
+}
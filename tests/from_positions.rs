@@ -0,0 +1,45 @@
+use bit_fiddler::{from_positions, BitError};
+
+#[test]
+fn from_positions_iterables() {
+    assert_eq!(from_positions!(u64, [0, 8, 63]), Ok(0x_8000_0000_0000_0101));
+    assert_eq!(from_positions!(u64, vec![1_u8, 2]), Ok(0b_0110));
+    assert_eq!(from_positions!(u64, &[1_i64, 2]), Ok(0b_0110));
+    assert_eq!(from_positions!(u16, (4..8).chain(12..13)), Ok(0x_10f0));
+    assert_eq!(from_positions!(u8, [1, 1, 1]), Ok(0b_0010));
+    assert_eq!(from_positions!(u8, Vec::<usize>::new()), Ok(0));
+}
+
+#[test]
+fn from_positions_rev() {
+    assert_eq!(from_positions!(u64, rev [0, 63]), Ok(0x_8000_0000_0000_0001));
+    assert_eq!(from_positions!(u8, rev 0..2), Ok(0b_1100_0000));
+    assert_eq!(from_positions!(u128, rev [127]), Ok(1));
+}
+
+#[test]
+fn from_positions_signed() {
+    assert_eq!(from_positions!(i8, [7]), Ok(i8::MIN));
+    assert_eq!(from_positions!(i16, 0..16), Ok(-1));
+    assert_eq!(from_positions!(i32, rev [0]), Ok(i32::MIN));
+}
+
+#[test]
+fn from_positions_errors() {
+    assert_eq!(
+        from_positions!(u32, [3, 32, 40]),
+        Err(BitError::PositionOutOfRange { pos: 32, max_bits: 32 })
+    );
+    assert_eq!(
+        from_positions!(u8, rev [8]),
+        Err(BitError::RevUnderflow { pos: 8, max_bits: 8 })
+    );
+    assert_eq!(
+        from_positions!(u8, [-1]),
+        Err(BitError::PositionOutOfRange { pos: usize::MAX, max_bits: 8 })
+    );
+    assert_eq!(
+        from_positions!(u8, [u128::MAX]),
+        Err(BitError::PositionOutOfRange { pos: usize::MAX, max_bits: 8 })
+    );
+}
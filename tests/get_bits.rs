@@ -0,0 +1,91 @@
+use bit_fiddler::get_bits;
+
+#[test]
+fn get_bits_range() {
+    let reg: u32 = 0b_1010_0110_0000;
+
+    assert_eq!(get_bits!(reg, u32, [4..8]), 0b_0110);
+    assert_eq!(get_bits!(reg, u32, [8..]), 0b_1010);
+    assert_eq!(get_bits!(reg, u32, [..8]), 0b_0110_0000);
+    assert_eq!(get_bits!(reg, u32, [..]), reg);
+    assert_eq!(get_bits!(reg, u32, [4..=11]), 0b_1010_0110);
+    assert_eq!(get_bits!(reg, u32, [start = 5, count = 3]), 0b_011);
+    assert_eq!(get_bits!(reg, u32, [6..6]), 0);
+}
+
+#[test]
+fn get_bits_rev() {
+    let reg: u16 = 0b_1100_1010_0000_0001;
+
+    assert_eq!(get_bits!(reg, u16, rev [0..4]), 0b_1100);
+    assert_eq!(get_bits!(reg, u16, rev [4..=7]), 0b_1010);
+    assert_eq!(get_bits!(reg, u16, rev [start = 12, count = 4]), 0b_0001);
+    assert_eq!(get_bits!(reg, u16, rev [8..]), 0b_0000_0001);
+    assert_eq!(get_bits!(reg, u16, rev 0), 1);
+    assert_eq!(get_bits!(reg, u16, rev 2), 0);
+}
+
+#[test]
+fn get_bits_single_bit() {
+    let reg: u8 = 0b_0000_0100;
+
+    assert_eq!(get_bits!(reg, u8, 2), 1);
+    assert_eq!(get_bits!(reg, u8, 3), 0);
+    assert_eq!(get_bits!(reg, 2), 1);
+}
+
+#[test]
+fn get_bits_inferred_type() {
+    let reg: u64 = 0x_dead_beef_0000_0000;
+
+    assert_eq!(get_bits!(reg, [32..64]), 0x_dead_beef);
+    assert_eq!(get_bits!(reg, rev [..16]), 0x_dead);
+    assert_eq!(get_bits!(reg, [start = 32, count = 8]), 0x_ef);
+}
+
+#[test]
+fn get_bits_converted() {
+    let reg: u32 = 0x_12ab_cd00;
+
+    let byte: u8 = get_bits!(reg, u32, [8..16] as u8);
+    assert_eq!(byte, 0x_cd);
+
+    let byte: u8 = get_bits!(reg, rev [0..8] as u8);
+    assert_eq!(byte, 0x_12);
+
+    let wide = get_bits!(reg, u32, rev [start = 8, count = 8] as u64);
+    assert_eq!(wide, 0x_ab_u64);
+
+    assert_eq!(get_bits!(0x_f0, u8, [4..] as i8), 0b_1111);
+}
+
+#[test]
+fn get_bits_signed() {
+    let reg: i8 = 0b_1010_0101_u8 as i8;
+
+    assert_eq!(get_bits!(reg, i8, [4..8]), 0b_1010);
+    assert_eq!(get_bits!(reg, rev [..1]), 1);
+    assert_eq!(get_bits!(reg, [..]), reg);
+    assert_eq!(get_bits!((-1), i32, [16..]), 0x_ffff);
+    assert_eq!(get_bits!((-1), i64, rev [..4]), 0b_1111);
+}
+
+#[test]
+fn get_bits_expressions() {
+    let fields = [4, 8];
+    let mut calls = 0;
+    let mut reg = || {
+        calls += 1;
+        0b_1111_0000_u16
+    };
+
+    assert_eq!(get_bits!((reg()), u16, [fields[0]..fields[1]]), 0b_1111);
+    assert_eq!(calls, 1);
+}
+
+const FIELD: u8 = get_bits!(0x_1234, u16, [4..12] as u8);
+
+#[test]
+fn get_bits_in_const() {
+    assert_eq!(FIELD, 0x_23);
+}
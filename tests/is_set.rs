@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use bit_fiddler::is_set;
 
 #[test]
@@ -121,3 +123,100 @@ fn is_set_counted_range_rev() {
     let res = is_set!(0b_0110_1100, u8, rev [start = 1, count = 5]);
     assert_eq!(res, false);
 }
+
+#[test]
+fn is_set_inferred_type() {
+    let bitmap: u32 = 0b_1100_0000_0000_0000_0000_0000_0000_0101;
+
+    assert!(is_set!(bitmap, 0));
+    assert!(!is_set!(bitmap, 1));
+    assert!(is_set!(bitmap, rev 0));
+    assert!(!is_set!(bitmap, rev 2));
+    assert!(is_set!(bitmap, [0, 2]));
+    assert!(is_set!(bitmap, rev [0, 1]));
+    assert!(!is_set!(bitmap, [0..3]));
+    assert!(is_set!(bitmap, rev [..2]));
+    assert!(is_set!(bitmap, rev [start = 0, count = 2]));
+    assert!(!is_set!(bitmap, [..]));
+
+    let bitmap = u64::MAX;
+    assert!(is_set!(bitmap, [..]));
+    assert!(is_set!(bitmap, rev [60..]));
+}
+
+#[test]
+fn is_set_mixed_selectors() {
+    let bitmap: u32 = (1 << 31) | (0b_111 << 12) | 0b_1111_0001;
+
+    assert_eq!(is_set!(bitmap, u32, [0, 4..8, rev 0, start = 12, count = 3]), true);
+    assert_eq!(is_set!(bitmap, [0, 4..9, rev 0]), false);
+    assert_eq!(is_set!(bitmap, rev [0, start = 31, count = 1]), true);
+}
+
+#[test]
+fn is_set_signed_types() {
+    assert_eq!(is_set!(0b_0000_1111, i8, [..4]), true);
+    assert_eq!(is_set!(0b_0000_1111, i8, rev [4..]), true);
+    assert_eq!(is_set!(0b_0000_1111, i8, [..5]), false);
+    assert_eq!(is_set!(0b_0000_0111, i8, rev [4..]), false);
+
+    let bitmap: i32 = i32::MAX;
+    assert_eq!(is_set!(bitmap, rev [1..]), true);
+    assert_eq!(is_set!(bitmap, rev [..1]), false);
+}
+
+#[test]
+fn is_set_empty_ranges() {
+    let end = 0;
+
+    assert_eq!(is_set!(0, u8, [..end]), true);
+    assert_eq!(is_set!(0, u8, [8..]), true);
+    assert_eq!(is_set!(0, u8, rev [4..4]), true);
+    assert_eq!(is_set!(0, u8, rev [start = 8, count = 0]), true);
+    assert_eq!(is_set!(0, u64, [64..64]), true);
+}
+
+#[test]
+fn is_set_full_range_respects_type() {
+    let bitmap: u32 = 0x_0000_00ff;
+
+    assert_eq!(is_set!(bitmap, u8, [..]), true);
+    assert_eq!(is_set!(bitmap, u8, rev [..]), true);
+    assert_eq!(is_set!(bitmap, u8, [..]), is_set!(bitmap, u8, [0..8]));
+    assert_eq!(is_set!(bitmap, u16, [..]), false);
+    assert_eq!(is_set!(bitmap, u16, [..]), is_set!(bitmap, u16, [0..16]));
+    assert_eq!(is_set!((-1), i8, [..]), true);
+}
+
+#[test]
+fn is_set_stepped_ranges() {
+    let bitmap: u8 = 0b_0101_0101;
+
+    assert_eq!(is_set!(bitmap, [0..8; step = 2]), true);
+    assert_eq!(is_set!(bitmap, [..; step = 1]), false);
+    assert_eq!(is_set!(bitmap, u8, rev [1..; step = 2]), true);
+    assert_eq!(is_set!(bitmap, u8, [start = 0, count = 4, step = 2]), true);
+    assert_eq!(is_set!(bitmap, u8, [start = 1, count = 4, step = 2]), false);
+}
+
+#[test]
+fn is_set_inclusive_ranges() {
+    let bitmap: u8 = 0b_1111_0000;
+
+    assert_eq!(is_set!(bitmap, [4..=7]), true);
+    assert_eq!(is_set!(bitmap, u8, [3..=7]), false);
+    assert_eq!(is_set!(bitmap, u8, rev [..=3]), true);
+    assert_eq!(is_set!(0b_1111_1111, u8, [0..=7]), true);
+}
+
+#[test]
+fn is_set_non_usize_positions() {
+    let bitmap: u8 = 0b_1110_0000;
+    let pos: u32 = 1;
+    let end: u8 = 3;
+
+    assert_eq!(is_set!(bitmap, u8, rev pos), true);
+    assert_eq!(is_set!(bitmap, u8, rev [..end]), true);
+    assert_eq!(is_set!(bitmap, u8, rev [start = pos, count = 2]), true);
+    assert_eq!(is_set!(bitmap, u8, rev [pos..end + 1]), false);
+}
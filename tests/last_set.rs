@@ -0,0 +1,42 @@
+use bit_fiddler::last_set;
+
+#[test]
+fn last_set_whole_bitmap() {
+    let bitmap: u32 = 0b_1010_0000;
+
+    assert_eq!(last_set!(bitmap), Some(7));
+    assert_eq!(last_set!(bitmap, u32, [..]), Some(7));
+    assert_eq!(last_set!(0, u8, [..]), None);
+
+    let bitmap: i16 = -1;
+    assert_eq!(last_set!(bitmap), Some(15));
+}
+
+#[test]
+fn last_set_selectors() {
+    let bitmap: u64 = 0x_8000_0000_0001_0f00;
+
+    assert_eq!(last_set!(bitmap, u64, 63), Some(63));
+    assert_eq!(last_set!(bitmap, u64, 62), None);
+    assert_eq!(last_set!(bitmap, [0..12, 16, 40..48]), Some(16));
+    assert_eq!(last_set!(bitmap, [start = 4, count = 8]), Some(11));
+    assert_eq!(last_set!(bitmap, [..16; step = 2]), Some(10));
+    assert_eq!(last_set!(bitmap, [4..4]), None);
+}
+
+#[test]
+fn last_set_rev_selectors() {
+    let bitmap: u64 = 0x_8000_0000_0001_0f00;
+
+    assert_eq!(last_set!(bitmap, rev [..]), Some(55));
+    assert_eq!(last_set!(bitmap, u64, rev [..52]), Some(47));
+    assert_eq!(last_set!(bitmap, rev 1), None);
+    assert_eq!(last_set!(bitmap, rev [1..47]), None);
+}
+
+const LAST: Option<u32> = last_set!(0b_0110, u8, [..]);
+
+#[test]
+fn last_set_in_const() {
+    assert_eq!(LAST, Some(2));
+}
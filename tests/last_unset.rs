@@ -0,0 +1,42 @@
+use bit_fiddler::last_unset;
+
+#[test]
+fn last_unset_whole_bitmap() {
+    let bitmap: u8 = 0b_1101_1111;
+
+    assert_eq!(last_unset!(bitmap), Some(5));
+    assert_eq!(last_unset!(bitmap, u8, [..]), Some(5));
+    assert_eq!(last_unset!(0xff, u8, [..]), None);
+    assert_eq!(last_unset!(0, u32, [..]), Some(31));
+
+    let bitmap: i16 = -1;
+    assert_eq!(last_unset!(bitmap), None);
+}
+
+#[test]
+fn last_unset_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert_eq!(last_unset!(bitmap, u16, 4), Some(4));
+    assert_eq!(last_unset!(bitmap, u16, 5), None);
+    assert_eq!(last_unset!(bitmap, [0..8, 12]), Some(4));
+    assert_eq!(last_unset!(bitmap, [start = 2, count = 8]), Some(9));
+    assert_eq!(last_unset!(bitmap, [..; step = 4]), Some(8));
+    assert_eq!(last_unset!(bitmap, [12..]), None);
+}
+
+#[test]
+fn last_unset_rev_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert_eq!(last_unset!(bitmap, rev [..]), Some(11));
+    assert_eq!(last_unset!(bitmap, u16, rev [..10]), Some(7));
+    assert_eq!(last_unset!(bitmap, rev [..4]), None);
+}
+
+const LAST: Option<u32> = last_unset!(0b_1001, u8, [..4]);
+
+#[test]
+fn last_unset_in_const() {
+    assert_eq!(LAST, Some(2));
+}
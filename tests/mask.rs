@@ -76,3 +76,170 @@ fn counted_range_with_bit_count_rev() {
     let masked_bitmap = bitmap & mask!(rev [start = 0, count = 8], u8);
     assert_eq!(masked_bitmap, 0b_1111_1111);
 }
+
+#[test]
+fn single_and_multiple_bits() {
+    assert_eq!(mask!(3, u8), 0b_0000_1000);
+    assert_eq!(mask!(rev 3, u8), 0b_0001_0000);
+    assert_eq!(mask!([0, 3, 7], u8), 0b_1000_1001);
+    assert_eq!(mask!(rev [0, 3, 7], u8), 0b_1001_0001);
+    assert_eq!(mask!(rev 0, u32), 1 << 31);
+}
+
+#[test]
+fn expression_bounds() {
+    let base = 2;
+    let width = 3;
+
+    assert_eq!(mask!(base + 1, u8), 0b_0000_1000);
+    assert_eq!(mask!([base..base + width], u8), 0b_0001_1100);
+    assert_eq!(mask!(rev [base..base + width], u8), 0b_0011_1000);
+    assert_eq!(mask!([start = base * 2, count = width - 1], u8), 0b_0011_0000);
+    assert_eq!(mask!([base - 2, width + 4], u8), 0b_1000_0001);
+}
+
+#[test]
+fn non_usize_positions() {
+    let pos: u32 = 1;
+    let end: u8 = 3;
+
+    assert_eq!(mask!(rev pos, u8), 0b_0100_0000);
+    assert_eq!(mask!(rev [..end], u8), 0b_1110_0000);
+    assert_eq!(mask!(rev [pos..end], u8), 0b_0110_0000);
+    assert_eq!(mask!(rev [start = pos, count = 2], u8), 0b_0110_0000);
+    assert_eq!(mask!(rev [pos, 3], u8), 0b_0101_0000);
+    assert_eq!(mask!([pos..end], u8), 0b_0000_0110);
+}
+
+#[test]
+fn mixed_selectors() {
+    assert_eq!(mask!([0, 4..8, rev 0], u8), 0b_1111_0001);
+    assert_eq!(mask!([..2, start = 4, count = 2, rev 1], u8), 0b_0111_0011);
+    assert_eq!(mask!(rev [0, 6.., rev start = 2, count = 1], u8), 0b_1010_0011);
+    assert_eq!(mask!([2..4,], u8), mask!([2..4], u8));
+}
+
+#[test]
+fn signed_types() {
+    assert_eq!(mask!([..4], i8), 0b_0000_1111);
+    assert_eq!(mask!([2..6], i8), 0b_0011_1100);
+    assert_eq!(mask!([4..], i8), 0b_1111_0000_u8 as i8);
+    assert_eq!(mask!([..8], i8), -1);
+    assert_eq!(mask!(rev [..4], i8), 0b_1111_0000_u8 as i8);
+    assert_eq!(mask!(rev [4..], i8), 0b_0000_1111);
+    assert_eq!(mask!(rev [1..3], i8), 0b_0110_0000);
+    assert_eq!(mask!(rev [start = 1, count = 7], i8), 0b_0111_1111);
+    assert_eq!(mask!(rev 0, i8), i8::MIN);
+
+    assert_eq!(mask!([..16], i16), -1);
+    assert_eq!(mask!(rev [1..], i16), i16::MAX);
+    assert_eq!(mask!([..31], i32), i32::MAX);
+    assert_eq!(mask!(rev [..1], i32), i32::MIN);
+    assert_eq!(mask!([60..63], i64), 0b_0111 << 60);
+    assert_eq!(mask!(rev [1..], i128), i128::MAX);
+    assert_eq!(mask!([..4], i128), 0b_1111);
+    assert_eq!(mask!(rev [4..], isize), isize::MAX >> 3);
+    assert_eq!(mask!([2..4], isize), 0b_1100);
+}
+
+#[test]
+fn empty_ranges() {
+    let zero = 0;
+
+    assert_eq!(mask!([..zero], u8), 0);
+    assert_eq!(mask!([zero..zero], u8), 0);
+    assert_eq!(mask!([8..], u8), 0);
+    assert_eq!(mask!([8..8], u8), 0);
+    assert_eq!(mask!([3..3], u8), 0);
+    assert_eq!(mask!(rev [..zero], u8), 0);
+    assert_eq!(mask!(rev [8..], u8), 0);
+    assert_eq!(mask!(rev [5..5], u8), 0);
+    assert_eq!(mask!([start = 8, count = 0], u8), 0);
+    assert_eq!(mask!(rev [start = 0, count = 0], u8), 0);
+
+    assert_eq!(mask!([..0], i8), 0);
+    assert_eq!(mask!(rev [16..], u16), 0);
+    assert_eq!(mask!([32..], i32), 0);
+    assert_eq!(mask!(rev [..0], u64), 0);
+    assert_eq!(mask!([128..128], i128), 0);
+    assert_eq!(mask!([start = 0, count = 0], usize), 0);
+}
+
+#[test]
+fn full_width_ranges() {
+    assert_eq!(mask!([0..8], u8), u8::MAX);
+    assert_eq!(mask!(rev [0..8], u8), u8::MAX);
+    assert_eq!(mask!([..16], u16), u16::MAX);
+    assert_eq!(mask!(rev [..32], i32), -1);
+    assert_eq!(mask!([0..], u64), u64::MAX);
+    assert_eq!(mask!(rev [0..], i64), -1);
+    assert_eq!(mask!([start = 0, count = 128], u128), u128::MAX);
+    assert_eq!(mask!(rev [start = 0, count = 128], i128), -1);
+}
+
+#[test]
+fn every_range_of_u8() {
+    for start in 0..=8usize {
+        for end in start..=8 {
+            let expected = (start..end).fold(0u8, |mask, pos| mask | (1 << pos));
+            let expected_rev = (start..end).fold(0u8, |mask, pos| mask | (0b_1000_0000 >> pos));
+
+            assert_eq!(mask!([start..end], u8), expected);
+            assert_eq!(mask!(rev [start..end], u8), expected_rev);
+            assert_eq!(mask!([start = start, count = end - start], u8), expected);
+            assert_eq!(mask!(rev [start = start, count = end - start], u8), expected_rev);
+            assert_eq!(mask!([start..end], i8), expected as i8);
+            assert_eq!(mask!(rev [start..end], i8), expected_rev as i8);
+        }
+        assert_eq!(mask!([start..], u8), mask!([start..8], u8));
+        assert_eq!(mask!([..start], u8), mask!([0..start], u8));
+        assert_eq!(mask!(rev [start..], u8), mask!(rev [start..8], u8));
+        assert_eq!(mask!(rev [..start], u8), mask!(rev [0..start], u8));
+    }
+}
+
+#[test]
+fn stepped_ranges() {
+    assert_eq!(mask!([0..8; step = 2], u8), 0b_0101_0101);
+    assert_eq!(mask!([1..8; step = 2], u8), 0b_1010_1010);
+    assert_eq!(mask!([..; step = 4], u8), 0b_0001_0001);
+    assert_eq!(mask!([2..; step = 3], u8), 0b_0010_0100);
+    assert_eq!(mask!([..5; step = 2], u8), 0b_0001_0101);
+    assert_eq!(mask!([3..3; step = 2], u8), 0);
+    assert_eq!(mask!(rev [0..8; step = 2], u8), 0b_1010_1010);
+    assert_eq!(mask!(rev [..; step = 3], u8), 0b_1001_0010);
+    assert_eq!(mask!([start = 1, count = 3, step = 2], u8), 0b_0010_1010);
+    assert_eq!(mask!(rev [start = 0, count = 2, step = 4], u8), 0b_1000_1000);
+    assert_eq!(mask!([start = 7, count = 0, step = 2], u8), 0);
+    assert_eq!(mask!([0..32; step = 2], u32), 0x_5555_5555);
+    assert_eq!(mask!([0..64; step = 4], i64), 0x_1111_1111_1111_1111);
+    assert_eq!(mask!(rev [0..1; step = 8], i8), i8::MIN);
+
+    let (base, width, step) = (4, 8, 2);
+    assert_eq!(mask!([base..base + width; step = step], u16), 0b_0101_0101_0000);
+    assert_eq!(mask!([0, 8..16; step = 4, rev 0], u16), 0b_1001_0001_0000_0001);
+    assert_eq!(mask!([start = 0, count = 2, step = 3, 7], u8), 0b_1000_1001);
+}
+
+#[test]
+fn inclusive_ranges() {
+    assert_eq!(mask!([4..=7], u8), 0b_1111_0000);
+    assert_eq!(mask!([..=3], u8), 0b_0000_1111);
+    assert_eq!(mask!([0..=7], u8), u8::MAX);
+    assert_eq!(mask!([0..=7], i8), -1);
+    assert_eq!(mask!([2..=2], u8), 0b_0000_0100);
+    assert_eq!(mask!(rev [0..=2], u8), 0b_1110_0000);
+    assert_eq!(mask!(rev [..=7], u8), u8::MAX);
+    assert_eq!(mask!([0..=63], u64), u64::MAX);
+    assert_eq!(mask!([0..=127], i128), -1);
+    assert_eq!(mask!([0..=7; step = 2], u8), 0b_0101_0101);
+    assert_eq!(mask!([1, 4..=5, rev 0], u8), 0b_1011_0010);
+
+    let (high, low) = (7, 4);
+    assert_eq!(mask!([low..=high], u16), 0b_1111_0000);
+    assert_eq!(mask!([low..=high - 1], u16), 0b_0111_0000);
+
+    let last: i8 = 127;
+    assert_eq!(mask!([0..=last], u128), u128::MAX);
+    assert_eq!(mask!(rev [..=last], i128), -1);
+}
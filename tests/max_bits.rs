@@ -9,6 +9,7 @@ fn max_bits_for_ident() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_cast)]
 fn max_bits_for_literal() {
     let max_bits = max_bits!((9 as u8));
     assert_eq!(max_bits, 8);
@@ -0,0 +1,49 @@
+use bit_fiddler::{is_set, missing_bits, ones};
+
+#[test]
+fn missing_bits_single_and_multiple_bits() {
+    let bitmap: u8 = 0b_0010_0010;
+
+    assert_eq!(missing_bits!(bitmap, u8, 1), 0);
+    assert_eq!(missing_bits!(bitmap, u8, 2), 0b_0000_0100);
+    assert_eq!(missing_bits!(bitmap, [0, 1, 5, 7]), 0b_1000_0001);
+    assert_eq!(missing_bits!(bitmap, rev 2), 0);
+    assert_eq!(missing_bits!(bitmap, u8, rev [0, 2]), 0b_1000_0000);
+}
+
+#[test]
+fn missing_bits_ranges() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert_eq!(missing_bits!(bitmap, u64, [8..16]), 0x_f000);
+    assert_eq!(missing_bits!(bitmap, [start = 0, count = 4]), 0);
+    assert_eq!(missing_bits!(bitmap, rev [..=8]), 0x_0080_0000_0000_0000);
+    assert_eq!(missing_bits!(bitmap, [0..16; step = 4]), 0x_1010);
+    assert_eq!(missing_bits!(bitmap, [4..4]), 0);
+}
+
+#[test]
+fn missing_bits_matches_is_set() {
+    let status: u32 = 0b_1101_1111_0111;
+
+    assert_eq!(missing_bits!(status, [0, 3, 7..12]) == 0, is_set!(status, [0, 3, 7..12]));
+    assert_eq!(missing_bits!(status, [0..3, 4]) == 0, is_set!(status, [0..3, 4]));
+
+    let missing = missing_bits!(status, u32, [0, 3, 7..12]);
+    assert!(ones!(missing).eq([3, 9]));
+}
+
+#[test]
+fn missing_bits_signed() {
+    let bitmap: i16 = 0;
+
+    assert_eq!(missing_bits!(bitmap, rev 0), i16::MIN);
+    assert_eq!(missing_bits!(bitmap, i16, [..]), -1);
+}
+
+const MISSING: u8 = missing_bits!(0b_1010, u8, [0..4]);
+
+#[test]
+fn missing_bits_in_const() {
+    assert_eq!(MISSING, 0b_0101);
+}
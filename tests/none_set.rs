@@ -0,0 +1,44 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::none_set;
+
+#[test]
+fn none_set_single_and_multiple_bits() {
+    let bitmap: u8 = 0b_0010_0010;
+
+    assert!(!none_set!(bitmap, u8, 1));
+    assert!(none_set!(bitmap, u8, 2));
+    assert!(!none_set!(bitmap, [0, 5, 7]));
+    assert!(none_set!(bitmap, u8, [0, 2, 7]));
+    assert!(!none_set!(bitmap, rev 2));
+    assert!(none_set!(bitmap, rev [0, 1, 3]));
+}
+
+#[test]
+fn none_set_ranges() {
+    let bitmap: u64 = 0x_0100_0000_0000_0800;
+
+    assert!(!none_set!(bitmap, u64, [8..16]));
+    assert!(none_set!(bitmap, [12..56]));
+    assert!(!none_set!(bitmap, [start = 50, count = 7]));
+    assert!(none_set!(bitmap, rev [..=6]));
+    assert!(!none_set!(bitmap, rev [start = 7, count = 1]));
+    assert!(none_set!(bitmap, [1..; step = 4]));
+    assert!(none_set!(bitmap, [4..4]));
+}
+
+#[test]
+fn none_set_mixed_selectors_and_signed() {
+    let bitmap: i16 = i16::MIN;
+
+    assert!(!none_set!(bitmap, [0, 1..3, rev 0]));
+    assert!(none_set!(bitmap, i16, [0..15]));
+    assert!(none_set!(0, i32, [..]));
+}
+
+const NONE: bool = none_set!(0b_1000, u8, [0..3]);
+
+#[test]
+fn none_set_in_const() {
+    assert_eq!(NONE, true);
+}
@@ -0,0 +1,44 @@
+use bit_fiddler::nth_set;
+
+#[test]
+fn nth_set_whole_bitmap() {
+    let bitmap: u32 = 0b_1011_0100;
+
+    assert_eq!(nth_set!(bitmap, 0), Some(2));
+    assert_eq!(nth_set!(bitmap, 1), Some(4));
+    assert_eq!(nth_set!(bitmap, u32, 3), Some(7));
+    assert_eq!(nth_set!(bitmap, u32, 4), None);
+    assert_eq!(nth_set!(0, u8, 0), None);
+
+    let bitmap: i8 = -1;
+    assert_eq!(nth_set!(bitmap, 7), Some(7));
+    assert_eq!(nth_set!(bitmap, 8), None);
+}
+
+#[test]
+fn nth_set_selectors() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert_eq!(nth_set!(bitmap, 0, [4..]), Some(8));
+    assert_eq!(nth_set!(bitmap, u64, 5, [4..]), Some(57));
+    assert_eq!(nth_set!(bitmap, 2, [0, 8, 12..]), Some(56));
+    assert_eq!(nth_set!(bitmap, 1, [0..; step = 3]), Some(3));
+    assert_eq!(nth_set!(bitmap, 0, [12..56]), None);
+}
+
+#[test]
+fn nth_set_rev_selectors() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert_eq!(nth_set!(bitmap, 0, rev [..]), Some(0));
+    assert_eq!(nth_set!(bitmap, 8, rev [..]), Some(52));
+    assert_eq!(nth_set!(bitmap, u64, 1, rev [4..]), Some(5));
+    assert_eq!(nth_set!(bitmap, u64, 16, rev [..]), None);
+}
+
+const NTH: Option<u32> = nth_set!(0b_0110, u8, 1);
+
+#[test]
+fn nth_set_in_const() {
+    assert_eq!(NTH, Some(2));
+}
@@ -0,0 +1,62 @@
+use bit_fiddler::ones;
+
+#[test]
+fn ones_whole_bitmap() {
+    let bitmap: u32 = 0b_1011_0100;
+
+    assert!(ones!(bitmap).eq([2, 4, 5, 7]));
+    assert!(ones!(bitmap, u32).eq([2, 4, 5, 7]));
+    assert!(ones!(bitmap, u32, [..]).eq([2, 4, 5, 7]));
+    assert_eq!(ones!(0, u8).next(), None);
+    assert_eq!(ones!((u128::MAX), u128).last(), Some(127));
+}
+
+#[test]
+fn ones_signed() {
+    let bitmap: i8 = -1;
+    assert!(ones!(bitmap).eq(0..8));
+
+    let bitmap: i64 = i64::MIN;
+    assert!(ones!(bitmap).eq([63]));
+    assert!(ones!(bitmap, rev [..]).eq([0]));
+}
+
+#[test]
+fn ones_selectors() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert!(ones!(bitmap, [4..12]).eq([8, 9, 10, 11]));
+    assert!(ones!(bitmap, u64, [0, 8, 60..]).eq([0, 8, 60, 61, 62, 63]));
+    assert!(ones!(bitmap, [..; step = 3]).eq([0, 3, 9, 57, 60, 63]));
+    assert!(ones!(bitmap, [12..56]).eq([]));
+}
+
+#[test]
+fn ones_rev_selectors() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+
+    assert!(ones!(bitmap, rev [6..]).eq([6, 7, 52, 53, 54, 55, 60, 61, 62, 63]));
+    assert!(ones!(bitmap, u64, rev [..2]).eq([0, 1]));
+    assert!(ones!(bitmap, rev [..2]).rev().eq([1, 0]));
+}
+
+#[test]
+fn ones_double_ended_and_exact_size() {
+    let bitmap: u16 = 0b_1000_0110_0001_0000;
+
+    let mut iter = ones!(bitmap, u16);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next_back(), Some(15));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(10));
+    assert_eq!(iter.next(), Some(9));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = ones!(bitmap, u16, rev [..]);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(11));
+    assert!(iter.eq([5, 6]));
+}
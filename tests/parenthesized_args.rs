@@ -0,0 +1,80 @@
+#![deny(warnings)]
+
+use bit_fiddler::{
+    any_set, assign, bits, checked_put_bits, checked_set, count_set, first_set, get_bits, is_set, mask, ones,
+    put_bits, set, toggle, unset,
+};
+
+#[test]
+fn parenthesized_positions() {
+    let idx: u32 = 1;
+    let mut bitmap: u32 = 0;
+
+    set!(in bitmap, u32, (idx + 1));
+    set!(in bitmap, u32, rev (idx - 1));
+    toggle!(in bitmap, u32, [(idx), (idx + 3)]);
+    unset!(in bitmap, u32, rev [(idx - 1), (idx)]);
+    assert_eq!(bitmap, 0b_0001_0110);
+
+    assert!(is_set!(bitmap, u32, (idx + 3)));
+    assert_eq!(mask!((idx + 1), u32), 0b_0100);
+    assert_eq!(checked_set!(0, u32, (idx + 1)), Ok(0b_0100));
+}
+
+#[test]
+fn parenthesized_range_bounds() {
+    let (idx, width): (u32, u32) = (2, 3);
+    let mut bitmap: u32 = 0;
+
+    set!(in bitmap, u32, [(idx)..(idx + width)]);
+    assert_eq!(bitmap, 0b_0001_1100);
+
+    set!(in bitmap, u32, [(idx + width)..]);
+    unset!(in bitmap, u32, [..(idx)]);
+    toggle!(in bitmap, u32, rev [(idx)..(idx + width)]);
+    unset!(in bitmap, u32, [start = (idx), count = (width)]);
+    assert_eq!(bitmap, 0b_1100_0111_1111_1111_1111_1111_1110_0000);
+
+    assert_eq!(set!(0, u32, [(idx)..=(idx + 1)]), 0b_1100);
+    assert_eq!(mask!([(idx)..(idx + width)], u32), 0b_0001_1100);
+    assert_eq!(checked_set!(0, u32, [(idx)..(idx + width)]), Ok(0b_0001_1100));
+}
+
+#[test]
+fn parenthesized_values() {
+    let value: u32 = 2;
+    let mut bitmap: u32 = 0;
+
+    put_bits!(in bitmap, [0..4] = (value + 1));
+    assert_eq!(put_bits!(bitmap, [4..8] = (value * 2)), 0b_0100_0011);
+    assert_eq!(checked_put_bits!(in bitmap, [8..12] = (value)), Ok(0b_0011));
+    assert_eq!(bitmap, 0b_0010_0000_0011);
+}
+
+#[test]
+fn parenthesized_bitmaps() {
+    let a: u8 = 0b_0001;
+
+    assert_eq!(set!((a | 2), 3), 0b_1011);
+    assert_eq!(unset!((a | 2), [0..1]), 0b_0010);
+    assert_eq!(toggle!((a | 2), rev 0), 0b_1000_0011);
+    assert!(is_set!((a | 2), [0, 1]));
+    assert!(any_set!((a << 1), [1..4]));
+    assert_eq!(count_set!((a | 6), [..]), 3);
+    assert_eq!(first_set!((a << 2), [..]), Some(2));
+    assert_eq!(get_bits!((a | 6), [1..3]), 0b_11);
+    assert_eq!(put_bits!((a | 2), [4..8] = 0b_0101), 0b_0101_0011);
+    assert_eq!(bits!((a | 2), { set 7, unset 0 }), 0b_1000_0010);
+    assert_eq!(checked_set!((a | 2), 2), Ok(0b_0111));
+    assert_eq!(ones!((a << 1 | 4)).count(), 2);
+}
+
+#[test]
+fn parenthesized_flags() {
+    let (enable, idx) = (true, 2);
+    let mut bitmap: u8 = 0;
+
+    assign!(in bitmap, u8, [0..4], (enable && idx > 0));
+    assign!(in bitmap, idx, (!enable));
+    assert_eq!(bitmap, 0b_1011);
+}
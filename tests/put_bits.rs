@@ -0,0 +1,99 @@
+use bit_fiddler::put_bits;
+
+#[test]
+fn put_bits_range() {
+    let reg: u32 = 0b_1111_0000_1111;
+
+    assert_eq!(put_bits!(reg, u32, [4..8] = 0b_1010), 0b_1111_1010_1111);
+    assert_eq!(put_bits!(reg, u32, [..4] = 0), 0b_1111_0000_0000);
+    assert_eq!(put_bits!(reg, u32, [8..] = 0), 0b_0000_0000_1111);
+    assert_eq!(put_bits!(reg, u32, [4..=7] = 0b_0110), 0b_1111_0110_1111);
+    assert_eq!(put_bits!(reg, u32, [start = 2, count = 4] = 0b_0101), 0b_1111_0001_0111);
+    assert_eq!(put_bits!(reg, u32, [..] = 7), 7);
+    assert_eq!(put_bits!(reg, u32, [4..4] = 0b_1111), reg);
+}
+
+#[test]
+fn put_bits_rev() {
+    let reg: u8 = 0b_0000_0000;
+
+    assert_eq!(put_bits!(reg, u8, rev [..4] = 0b_1010), 0b_1010_0000);
+    assert_eq!(put_bits!(reg, u8, rev [2..6] = 0b_1111), 0b_0011_1100);
+    assert_eq!(put_bits!(reg, u8, rev [start = 6, count = 2] = 0b_11), 0b_0000_0011);
+    assert_eq!(put_bits!(reg, rev [0..=0] = 1), 0b_1000_0000);
+}
+
+#[test]
+fn put_bits_in_place() {
+    let mut reg: u16 = 0xffff;
+
+    put_bits!(in reg, u16, [4..12] = 0x_a5);
+    assert_eq!(reg, 0x_fa5f);
+
+    put_bits!(in reg, rev [..4] = 0);
+    assert_eq!(reg, 0x_0a5f);
+
+    put_bits!(in reg, [0..4] = 0b_0011);
+    assert_eq!(reg, 0x_0a53);
+
+    let mut regs = [0_u8; 2];
+    put_bits!(in regs[1], u8, rev [start = 0, count = 2] = 0b_10);
+    assert_eq!(regs, [0, 0b_1000_0000]);
+}
+
+#[test]
+fn put_bits_truncates() {
+    let reg: u8 = 0;
+
+    assert_eq!(put_bits!(reg, u8, [2..4] = 0b_1111), 0b_0000_1100);
+    assert_eq!(put_bits!(reg, u8, rev [..2] = 0b_1111), 0b_1100_0000);
+    assert_eq!(put_bits!(reg, u8, [6..] = 0b_0111), 0b_1100_0000);
+    assert_eq!(put_bits!(reg, u8, [4..4] = 0b_1111), 0);
+}
+
+#[test]
+fn put_bits_signed() {
+    let reg: i8 = -1;
+
+    assert_eq!(put_bits!(reg, i8, [..4] = 0), 0b_1111_0000_u8 as i8);
+    assert_eq!(put_bits!(reg, rev [..1] = 0), i8::MAX);
+    assert_eq!(put_bits!(0, i32, [28..] = 0b_1111), 0b_1111 << 28);
+    assert_eq!(put_bits!(0, i32, [28..] = -1), 0b_1111 << 28);
+}
+
+#[test]
+fn put_bits_value_expressions() {
+    let (channel, value) = (2, 0b_11_u32);
+    let mut regs = [0_u32, 0b_10];
+
+    put_bits!(in regs[0], u32, [channel * 4..channel * 4 + 4] = value + 1);
+    assert_eq!(regs[0], 0b_0100 << 8);
+
+    // The value is read before the bitmap is borrowed.
+    put_bits!(in regs[0], [..2] = regs[1]);
+    assert_eq!(regs[0], (0b_0100 << 8) | 0b_10);
+}
+
+const REG: u32 = put_bits!(0x_ffff_ffff, u32, [8..16] = 0);
+
+#[test]
+fn put_bits_in_const() {
+    assert_eq!(REG, 0x_ffff_00ff);
+}
+
+#[test]
+fn put_bits_in_returns_previous() {
+    let mut reg: u16 = 0x_1234;
+
+    assert_eq!(put_bits!(in reg, u16, [4..8] = 0x_f), 0x_1234);
+    assert_eq!(put_bits!(in reg, rev [..4] = 0x_a), 0x_12f4);
+    assert_eq!(reg, 0x_a2f4);
+}
+
+#[test]
+fn put_bits_in_selector_reads_bitmap() {
+    let mut reg: u16 = 0x_0004;
+    put_bits!(in reg, u16, [reg..reg + 4] = 0xf);
+    put_bits!(in reg, [(reg >> 6)..4] = 1);
+    assert_eq!(reg, 0x_00fc);
+}
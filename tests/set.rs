@@ -135,3 +135,245 @@ fn set_counted_range_rev() {
     set!(in bitmap, u8, rev [start = 1, count = 5]);
     assert_eq!(bitmap, 0b_0111_1100);
 }
+
+struct Regs {
+    ctrl: u8,
+    data: [u8; 4],
+}
+
+impl Regs {
+    fn ctrl_mut(&mut self) -> &mut u8 {
+        &mut self.ctrl
+    }
+}
+
+#[test]
+fn set_place_expressions() {
+    let mut regs = Regs { ctrl: 0, data: [0; 4] };
+
+    set!(in regs.ctrl, u8, 0);
+    assert_eq!(regs.ctrl, 0b_0000_0001);
+
+    set!(in regs.data[1], u8, rev [0, 1]);
+    assert_eq!(regs.data[1], 0b_1100_0000);
+
+    set!(in regs.data[2], u8, [2..4]);
+    assert_eq!(regs.data[2], 0b_0000_1100);
+
+    set!(in *regs.ctrl_mut(), u8, rev [start = 0, count = 2]);
+    assert_eq!(regs.ctrl, 0b_1100_0001);
+
+    let ptr = &mut regs.data[3];
+    set!(in *ptr, u8, [..]);
+    assert_eq!(regs.data[3], u8::MAX);
+}
+
+#[test]
+fn set_place_evaluated_once() {
+    let mut data = [0; 4];
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+
+    set!(in data[next()], u8, [1..3]);
+    assert_eq!(calls, 1);
+    assert_eq!(data[1], 0b_0000_0110);
+}
+
+#[test]
+fn set_inferred_type() {
+    let bitmap: u32 = 0;
+
+    assert_eq!(set!(bitmap, 4), 0b_0001_0000);
+    assert_eq!(set!(bitmap, rev 0), 1 << 31);
+    assert_eq!(set!(bitmap, [0, 2]), 0b_0101);
+    assert_eq!(set!(bitmap, rev [0, 1]), 0b_11 << 30);
+    assert_eq!(set!(bitmap, [4..8]), 0b_1111_0000);
+    assert_eq!(set!(bitmap, rev [..4]), 0b_1111 << 28);
+    assert_eq!(set!(bitmap, [start = 1, count = 2]), 0b_0110);
+    assert_eq!(set!(bitmap, [..]), u32::MAX);
+
+    let mut bitmap: u64 = 0;
+
+    set!(in bitmap, rev 0);
+    assert_eq!(bitmap, 1 << 63);
+
+    set!(in bitmap, [0..4]);
+    assert_eq!(bitmap, (1 << 63) | 0b_1111);
+
+    let mut regs: [u16; 2] = [0; 2];
+    set!(in regs[1], rev [start = 0, count = 4]);
+    assert_eq!(regs[1], 0b_1111 << 12);
+}
+
+#[test]
+fn set_expression_positions() {
+    let idx = 2;
+    let base = 4;
+    let width = 3;
+
+    let mut bitmap = 0;
+    set!(in bitmap, u32, idx + 1);
+    assert_eq!(bitmap, 0b_1000);
+
+    assert_eq!(set!(0, u32, [base..base + width]), 0b_0111_0000);
+    assert_eq!(set!(0, u32, [..base - 1]), 0b_0111);
+    assert_eq!(set!(0, u8, [base * 2 - 2..]), 0b_1100_0000);
+    assert_eq!(set!(0, u8, rev idx * 2), 0b_0000_1000);
+    assert_eq!(set!(0, u8, [idx - 1, idx + 1]), 0b_1010);
+    assert_eq!(set!(0, u8, rev [start = idx - 2, count = width + 1]), 0b_1111_0000);
+
+    let bitmap: u16 = 0;
+    assert_eq!(set!(bitmap, idx + 1), 0b_1000);
+    assert_eq!(set!(bitmap, rev [base - 4..base]), 0b_1111 << 12);
+}
+
+#[test]
+fn set_non_usize_positions() {
+    let pos: u32 = 2;
+    let end: u8 = 4;
+
+    assert_eq!(set!(0, u8, rev pos), 0b_0010_0000);
+    assert_eq!(set!(0, u8, rev [..end]), 0b_1111_0000);
+    assert_eq!(set!(0, u8, rev [start = pos, count = 2]), 0b_0011_0000);
+
+    let mut bitmap: u16 = 0;
+    set!(in bitmap, u16, rev pos);
+    assert_eq!(bitmap, 1 << 13);
+}
+
+#[test]
+fn set_positions_evaluated_once() {
+    let mut calls = 0;
+    let mut pos = |p: usize| {
+        calls += 1;
+        p
+    };
+
+    assert_eq!(set!(0, u8, rev pos(1)), 0b_0100_0000);
+    assert_eq!(set!(0, u8, rev [pos(1)..pos(3)]), 0b_0110_0000);
+    assert_eq!(set!(0, u8, rev [start = pos(1), count = pos(2)]), 0b_0110_0000);
+    assert_eq!(set!(0, u8, [pos(6)..]), 0b_1100_0000);
+    assert_eq!(calls, 6);
+}
+
+#[test]
+fn set_mixed_selectors() {
+    let mut ctrl = 0;
+    set!(in ctrl, u32, [0, 4..8, rev 0, start = 12, count = 3]);
+    assert_eq!(ctrl, (1 << 31) | (0b_111 << 12) | 0b_1111_0001);
+
+    let base = 2;
+    assert_eq!(set!(0, u8, rev [0, ..base, start = 4, count = 2, rev start = 0, count = 1]), 0b_1100_1100);
+    assert_eq!(set!(0, u8, [1, 2, 6.., ..1,]), 0b_1100_0111);
+
+    let bitmap: u16 = 0;
+    assert_eq!(set!(bitmap, [0, base + 1..base + 3, rev 0]), 0b_1000_0000_0001_1001);
+}
+
+#[test]
+fn set_signed_types() {
+    assert_eq!(set!(0, i8, [..4]), 0b_0000_1111);
+    assert_eq!(set!(0, i8, rev [4..]), 0b_0000_1111);
+    assert_eq!(set!(0, i8, rev [1..3]), 0b_0110_0000);
+    assert_eq!(set!(0, i8, [7]), i8::MIN);
+
+    let mut bitmap: i32 = 0;
+    set!(in bitmap, [..31]);
+    assert_eq!(bitmap, i32::MAX);
+
+    let mut bitmap: i64 = 0;
+    set!(in bitmap, rev [start = 1, count = 2]);
+    assert_eq!(bitmap, 0b_0011 << 61);
+
+    let mut bitmap: isize = 0;
+    set!(in bitmap, rev [1..]);
+    assert_eq!(bitmap, isize::MAX);
+}
+
+#[test]
+fn set_empty_ranges() {
+    let (start, end) = (3, 3);
+    let mut bitmap: u8 = 0b_0101_0101;
+
+    set!(in bitmap, u8, [start..end]);
+    set!(in bitmap, u8, [..0]);
+    set!(in bitmap, rev [8..]);
+    set!(in bitmap, rev [start = 0, count = 0]);
+    assert_eq!(bitmap, 0b_0101_0101);
+}
+
+#[test]
+fn set_stepped_ranges() {
+    let mut bitmap: u32 = 0;
+    set!(in bitmap, u32, [0..32; step = 2]);
+    assert_eq!(bitmap, 0x_5555_5555);
+
+    let mut bitmap: u16 = 0;
+    set!(in bitmap, rev [start = 0, count = 4, step = 4]);
+    assert_eq!(bitmap, 0b_1000_1000_1000_1000);
+
+    assert_eq!(set!(0, u8, rev [1..; step = 2]), 0b_0101_0101);
+}
+
+#[test]
+fn set_inclusive_ranges() {
+    let mut bitmap: u32 = 0;
+    set!(in bitmap, u32, [4..=7]);
+    assert_eq!(bitmap, 0b_1111_0000);
+
+    set!(in bitmap, [0..=31]);
+    assert_eq!(bitmap, u32::MAX);
+
+    assert_eq!(set!(0, u8, rev [..=1]), 0b_1100_0000);
+}
+
+#[test]
+fn set_in_returns_previous() {
+    let mut bitmap: u16 = 0b_0001;
+
+    assert_eq!(set!(in bitmap, u16, [4..8]), 0b_0001);
+    assert_eq!(set!(in bitmap, rev 0), 0b_1111_0001);
+    assert_eq!(set!(in bitmap, 1), 0x_80f1);
+    assert_eq!(bitmap, 0x_80f3);
+}
+
+#[test]
+fn set_in_as_expression() {
+    let mut bitmap: u8 = 0;
+    let mask = 0b_1000_0000;
+
+    for enable in [true, false] {
+        match enable {
+            true => set!(in bitmap, u8, [1..3]),
+            false => set!(in bitmap, [start = 4, count = 2]),
+        };
+    }
+    let prev = if bitmap == 0 { 0 } else { set!(in bitmap, u8, rev 0) };
+
+    assert_eq!(prev, 0b_0011_0110);
+    assert_eq!(bitmap | mask, bitmap);
+}
+
+#[test]
+fn set_in_position_reads_bitmap() {
+    let mut reg: u32 = 0b_0010;
+    set!(in reg, u32, (reg & 7));
+    set!(in reg, [reg.count_ones()..4]);
+    assert_eq!(reg, 0b_1110);
+
+    let mut regs: [u8; 2] = [0, 5];
+    set!(in regs[0], u8, regs[1]);
+    set!(in regs[1], rev regs[1]);
+    assert_eq!(regs, [0b_0010_0000, 0b_0000_0101]);
+
+    let mut calls = 0;
+    let mut index = || {
+        calls += 1;
+        0
+    };
+    set!(in regs[index()], [0, 2]);
+    assert_eq!(calls, 1);
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::test_and_set;
+
+#[test]
+fn test_and_set_single_bit() {
+    let mut bitmap: u8 = 0b_1000_0000;
+
+    assert_eq!(test_and_set!(in bitmap, u8, 7), true);
+    assert_eq!(test_and_set!(in bitmap, u8, 0), false);
+    assert_eq!(test_and_set!(in bitmap, 0), true);
+    assert_eq!(test_and_set!(in bitmap, rev 1), false);
+    assert_eq!(test_and_set!(in bitmap, u8, rev 1), true);
+    assert_eq!(bitmap, 0b_1100_0001);
+}
+
+#[test]
+fn test_and_set_lists_and_ranges() {
+    let mut bitmap: u16 = 0b_0000_0000_1010_0000;
+
+    assert_eq!(test_and_set!(in bitmap, u16, [4..8]), 0b_1010_0000);
+    assert_eq!(bitmap, 0b_0000_0000_1111_0000);
+
+    assert_eq!(test_and_set!(in bitmap, [0, 7, 8]), 0b_1000_0000);
+    assert_eq!(test_and_set!(in bitmap, rev [start = 0, count = 4]), 0);
+    assert_eq!(test_and_set!(in bitmap, u16, rev [..=3]), 0xf000);
+    assert_eq!(test_and_set!(in bitmap, [..; step = 2]), 0b_0101_0001_0101_0001);
+    assert_eq!(test_and_set!(in bitmap, [4..4]), 0);
+    assert_eq!(bitmap, 0b_1111_0101_1111_0101);
+}
+
+#[test]
+fn test_and_set_place_expressions() {
+    let mut regs: [i8; 2] = [0, -1];
+    let idx = 1;
+
+    assert_eq!(test_and_set!(in regs[0], i8, rev 0), false);
+    assert_eq!(test_and_set!(in regs[idx], [0..8]), -1);
+    assert_eq!(regs, [i8::MIN, -1]);
+}
+
+#[test]
+fn test_and_set_position_reads_bitmap() {
+    let mut reg: u32 = 0b_0010;
+
+    assert_eq!(test_and_set!(in reg, u32, (reg & 7)), false);
+    assert_eq!(test_and_set!(in reg, [reg..8]), 0);
+    assert_eq!(reg, 0b_1100_0110);
+}
@@ -0,0 +1,39 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::test_and_toggle;
+
+#[test]
+fn test_and_toggle_single_bit() {
+    let mut bitmap: u8 = 0b_1000_0000;
+
+    assert_eq!(test_and_toggle!(in bitmap, u8, 7), true);
+    assert_eq!(test_and_toggle!(in bitmap, u8, 7), false);
+    assert_eq!(test_and_toggle!(in bitmap, 0), false);
+    assert_eq!(test_and_toggle!(in bitmap, rev 0), true);
+    assert_eq!(test_and_toggle!(in bitmap, u8, rev 1), false);
+    assert_eq!(bitmap, 0b_0100_0001);
+}
+
+#[test]
+fn test_and_toggle_lists_and_ranges() {
+    let mut bitmap: u16 = 0b_0000_0000_1010_0000;
+
+    assert_eq!(test_and_toggle!(in bitmap, u16, [4..8]), 0b_1010_0000);
+    assert_eq!(bitmap, 0b_0000_0000_0101_0000);
+
+    assert_eq!(test_and_toggle!(in bitmap, [4, 6, 8]), 0b_0101_0000);
+    assert_eq!(test_and_toggle!(in bitmap, rev [start = 0, count = 4]), 0);
+    assert_eq!(test_and_toggle!(in bitmap, u16, rev [..=3]), 0xf000);
+    assert_eq!(test_and_toggle!(in bitmap, [8..8]), 0);
+    assert_eq!(bitmap, 0b_0000_0001_0000_0000);
+}
+
+#[test]
+fn test_and_toggle_place_expressions() {
+    let mut regs: [i8; 2] = [0, -1];
+    let idx = 1;
+
+    assert_eq!(test_and_toggle!(in regs[0], i8, rev 0), false);
+    assert_eq!(test_and_toggle!(in regs[idx], [0..8]), -1);
+    assert_eq!(regs, [i8::MIN, 0]);
+}
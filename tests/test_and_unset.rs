@@ -0,0 +1,37 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use bit_fiddler::test_and_unset;
+
+#[test]
+fn test_and_unset_single_bit() {
+    let mut bitmap: u8 = 0b_1100_0001;
+
+    assert_eq!(test_and_unset!(in bitmap, u8, 7), true);
+    assert_eq!(test_and_unset!(in bitmap, u8, 7), false);
+    assert_eq!(test_and_unset!(in bitmap, 0), true);
+    assert_eq!(test_and_unset!(in bitmap, rev 1), true);
+    assert_eq!(test_and_unset!(in bitmap, u8, rev 2), false);
+    assert_eq!(bitmap, 0);
+}
+
+#[test]
+fn test_and_unset_lists_and_ranges() {
+    let mut bitmap: u16 = 0xffff;
+
+    assert_eq!(test_and_unset!(in bitmap, u16, [4..8]), 0b_1111_0000);
+    assert_eq!(test_and_unset!(in bitmap, [0, 7, 8]), 0x_0101);
+    assert_eq!(test_and_unset!(in bitmap, rev [start = 0, count = 4]), 0xf000);
+    assert_eq!(test_and_unset!(in bitmap, u16, rev [..=3]), 0);
+    assert_eq!(test_and_unset!(in bitmap, [..; step = 2]), 0b_0000_0100_0000_0100);
+    assert_eq!(bitmap, 0b_0000_1010_0000_1010);
+}
+
+#[test]
+fn test_and_unset_place_expressions() {
+    let mut regs: [i8; 2] = [0, -1];
+    let idx = 1;
+
+    assert_eq!(test_and_unset!(in regs[idx], i8, rev 0), true);
+    assert_eq!(test_and_unset!(in regs[0], [0..8]), 0);
+    assert_eq!(regs, [0, i8::MAX]);
+}
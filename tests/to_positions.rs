@@ -0,0 +1,60 @@
+use bit_fiddler::to_positions;
+
+#[test]
+fn to_positions_into_buffer() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+    let mut buf = [0; 64];
+
+    let count = to_positions!(bitmap => &mut buf);
+    assert_eq!(count, 16);
+    assert_eq!(buf[..4], [0, 1, 2, 3]);
+    assert_eq!(buf[12..count], [60, 61, 62, 63]);
+
+    let count = to_positions!(bitmap, u64 => &mut buf);
+    assert_eq!(count, 16);
+
+    let count = to_positions!(bitmap, [4..12] => &mut buf);
+    assert_eq!(buf[..count], [8, 9, 10, 11]);
+
+    let count = to_positions!(bitmap, u64, [..; step = 4] => &mut buf);
+    assert_eq!(buf[..count], [0, 8, 56, 60]);
+
+    let count = to_positions!(0, u8 => &mut buf);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn to_positions_rev_into_buffer() {
+    let bitmap: u64 = 0x_ff00_0000_0000_0f0f;
+    let mut buf = [0; 64];
+
+    let count = to_positions!(bitmap, rev [6..] => &mut buf);
+    assert_eq!(buf[..count], [6, 7, 52, 53, 54, 55, 60, 61, 62, 63]);
+
+    let count = to_positions!(bitmap, u64, rev [..2] => &mut buf);
+    assert_eq!(buf[..count], [0, 1]);
+}
+
+#[test]
+fn to_positions_short_buffer() {
+    let bitmap: u8 = 0b_1011_0101;
+    let mut buf = [0; 3];
+
+    assert_eq!(to_positions!(bitmap => &mut buf), 3);
+    assert_eq!(buf, [0, 2, 4]);
+    assert_eq!(to_positions!(bitmap, u8 => &mut buf[..0]), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_positions_vec() {
+    let bitmap: i16 = -0x_1000;
+
+    assert_eq!(to_positions!(bitmap), [12, 13, 14, 15]);
+    assert_eq!(to_positions!(bitmap, i16), [12, 13, 14, 15]);
+    assert_eq!(to_positions!(bitmap, [..14]), [12, 13]);
+    assert_eq!(to_positions!(bitmap, rev [..]), [0, 1, 2, 3]);
+    assert_eq!(to_positions!(bitmap, i16, [13..; step = 2]), [13, 15]);
+    assert_eq!(to_positions!(bitmap, i16, rev [1..]), [1, 2, 3]);
+    assert!(to_positions!(0, u128).is_empty());
+}
@@ -155,3 +155,167 @@ fn toggle_counted_range_rev() {
     toggle!(in bitmap, u8, rev [start = 1, count = 5]);
     assert_eq!(bitmap, 0);
 }
+
+struct Regs {
+    ctrl: u8,
+    data: [u8; 4],
+}
+
+impl Regs {
+    fn ctrl_mut(&mut self) -> &mut u8 {
+        &mut self.ctrl
+    }
+}
+
+#[test]
+fn toggle_place_expressions() {
+    let mut regs = Regs { ctrl: 0b_0000_1111, data: [0b_0000_1111; 4] };
+
+    toggle!(in regs.ctrl, u8, 0);
+    assert_eq!(regs.ctrl, 0b_0000_1110);
+
+    toggle!(in regs.data[1], u8, rev [0, 1]);
+    assert_eq!(regs.data[1], 0b_1100_1111);
+
+    toggle!(in regs.data[2], u8, [2..4]);
+    assert_eq!(regs.data[2], 0b_0000_0011);
+
+    toggle!(in *regs.ctrl_mut(), u8, rev [start = 0, count = 2]);
+    assert_eq!(regs.ctrl, 0b_1100_1110);
+
+    let ptr = &mut regs.data[3];
+    toggle!(in *ptr, u8, [..]);
+    assert_eq!(regs.data[3], 0b_1111_0000);
+}
+
+#[test]
+fn toggle_place_evaluated_once() {
+    let mut data = [0b_0000_1111; 4];
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+
+    toggle!(in data[next()], u8, [1..3]);
+    assert_eq!(calls, 1);
+    assert_eq!(data[1], 0b_0000_1001);
+}
+
+#[test]
+fn toggle_inferred_type() {
+    let bitmap: u32 = 0b_1010;
+
+    assert_eq!(toggle!(bitmap, 1), 0b_1000);
+    assert_eq!(toggle!(bitmap, rev 0), (1 << 31) | 0b_1010);
+    assert_eq!(toggle!(bitmap, [0, 1]), 0b_1001);
+    assert_eq!(toggle!(bitmap, rev [0, 1]), (0b_11 << 30) | 0b_1010);
+    assert_eq!(toggle!(bitmap, [0..4]), 0b_0101);
+    assert_eq!(toggle!(bitmap, rev [..4]), (0b_1111 << 28) | 0b_1010);
+    assert_eq!(toggle!(bitmap, [start = 1, count = 2]), 0b_1100);
+    assert_eq!(toggle!(bitmap, [..]), !0b_1010);
+
+    let mut bitmap: u64 = 0b_1010;
+
+    toggle!(in bitmap, rev 0);
+    assert_eq!(bitmap, (1 << 63) | 0b_1010);
+
+    toggle!(in bitmap, [0..4]);
+    assert_eq!(bitmap, (1 << 63) | 0b_0101);
+
+    let mut regs: [u16; 2] = [0; 2];
+    toggle!(in regs[1], rev [start = 0, count = 4]);
+    assert_eq!(regs[1], 0b_1111 << 12);
+}
+
+#[test]
+fn toggle_mixed_selectors() {
+    let mut ctrl: u16 = 0b_0000_0000_1111_0000;
+    toggle!(in ctrl, [0, 4..6, rev 0]);
+    assert_eq!(ctrl, 0b_1000_0000_1100_0001);
+
+    // Overlapping selectors are folded into one mask before toggling.
+    assert_eq!(toggle!(0, u8, [1, 0..2]), 0b_0000_0011);
+}
+
+#[test]
+fn toggle_signed_types() {
+    assert_eq!(toggle!(0, i8, [..4]), 0b_0000_1111);
+    assert_eq!(toggle!(0, i8, rev [4..]), 0b_0000_1111);
+    assert_eq!(toggle!((-1), i8, [2..6]), !0b_0011_1100);
+
+    let mut bitmap: i32 = i32::MIN;
+    toggle!(in bitmap, rev [..]);
+    assert_eq!(bitmap, i32::MAX);
+
+    let mut bitmap: i64 = 0;
+    toggle!(in bitmap, rev [1..]);
+    assert_eq!(bitmap, i64::MAX);
+}
+
+#[test]
+fn toggle_empty_ranges() {
+    let (start, end) = (32, 32);
+    let mut bitmap: u32 = 0b_1010;
+
+    toggle!(in bitmap, [start..end]);
+    toggle!(in bitmap, u32, [..0]);
+    toggle!(in bitmap, rev [32..]);
+    toggle!(in bitmap, rev [start = 7, count = 0]);
+    assert_eq!(bitmap, 0b_1010);
+}
+
+#[test]
+fn toggle_stepped_ranges() {
+    let mut bitmap: u8 = 0b_0000_1111;
+    toggle!(in bitmap, [..; step = 2]);
+    assert_eq!(bitmap, 0b_0101_1010);
+
+    assert_eq!(toggle!(0, i8, rev [..4; step = 2]), 0b_1010_0000_u8 as i8);
+}
+
+#[test]
+fn toggle_inclusive_ranges() {
+    let mut bitmap: i16 = 0;
+    toggle!(in bitmap, [0..=15]);
+    assert_eq!(bitmap, -1);
+
+    assert_eq!(toggle!(0b_0000_1111, u8, rev [2..=5]), 0b_0011_0011);
+}
+
+#[test]
+fn toggle_in_returns_previous() {
+    let mut bitmap: u16 = 0x_00ff;
+
+    assert_eq!(toggle!(in bitmap, u16, [4..12]), 0x_00ff);
+    assert_eq!(toggle!(in bitmap, rev 0), 0x_0f0f);
+    assert_eq!(toggle!(in bitmap, 0), 0x_8f0f);
+    assert_eq!(bitmap, 0x_8f0e);
+}
+
+#[test]
+fn toggle_in_as_expression() {
+    let mut bitmap: u8 = 0;
+    let mask = 0b_0000_0001;
+
+    let prev = match bitmap {
+        0 => toggle!(in bitmap, u8, [1..3]),
+        _ => 0,
+    };
+
+    assert_eq!(prev, 0);
+    assert_eq!(bitmap & mask, 0);
+}
+
+#[test]
+fn toggle_in_position_reads_bitmap() {
+    let mut reg: u32 = 0b_0010;
+    toggle!(in reg, u32, (reg & 7));
+    toggle!(in reg, [reg.count_ones()..3]);
+    assert_eq!(reg, 0b_0010);
+
+    let mut regs: [u8; 2] = [0, 5];
+    toggle!(in regs[0], u8, regs[1]);
+    toggle!(in regs[0], rev regs[1]);
+    assert_eq!(regs[0], 0b_0010_0100);
+}
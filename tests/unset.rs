@@ -159,3 +159,166 @@ fn unset_counted_range_rev() {
     unset!(in bitmap, u8, rev [start = 1, count = 5]);
     assert_eq!(bitmap, 0);
 }
+
+struct Regs {
+    ctrl: u8,
+    data: [u8; 4],
+}
+
+impl Regs {
+    fn ctrl_mut(&mut self) -> &mut u8 {
+        &mut self.ctrl
+    }
+}
+
+#[test]
+fn unset_place_expressions() {
+    let mut regs = Regs { ctrl: 0b_1111_1111, data: [0b_1111_1111; 4] };
+
+    unset!(in regs.ctrl, u8, 0);
+    assert_eq!(regs.ctrl, 0b_1111_1110);
+
+    unset!(in regs.data[1], u8, rev [0, 1]);
+    assert_eq!(regs.data[1], 0b_0011_1111);
+
+    unset!(in regs.data[2], u8, [2..4]);
+    assert_eq!(regs.data[2], 0b_1111_0011);
+
+    unset!(in *regs.ctrl_mut(), u8, rev [start = 0, count = 2]);
+    assert_eq!(regs.ctrl, 0b_0011_1110);
+
+    let ptr = &mut regs.data[3];
+    unset!(in *ptr, u8, [..]);
+    assert_eq!(regs.data[3], 0);
+}
+
+#[test]
+fn unset_place_evaluated_once() {
+    let mut data = [0b_1111_1111; 4];
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+
+    unset!(in data[next()], u8, [1..3]);
+    assert_eq!(calls, 1);
+    assert_eq!(data[1], 0b_1111_1001);
+}
+
+#[test]
+fn unset_inferred_type() {
+    let bitmap: u32 = u32::MAX;
+
+    assert_eq!(unset!(bitmap, 4), !0b_0001_0000);
+    assert_eq!(unset!(bitmap, rev 0), !(1 << 31));
+    assert_eq!(unset!(bitmap, [0, 2]), !0b_0101);
+    assert_eq!(unset!(bitmap, rev [0, 1]), !(0b_11 << 30));
+    assert_eq!(unset!(bitmap, [4..8]), !0b_1111_0000);
+    assert_eq!(unset!(bitmap, rev [..4]), !(0b_1111 << 28));
+    assert_eq!(unset!(bitmap, [start = 1, count = 2]), !0b_0110);
+    assert_eq!(unset!(bitmap, [..]), 0);
+
+    let mut bitmap: u64 = u64::MAX;
+
+    unset!(in bitmap, rev 0);
+    assert_eq!(bitmap, !(1 << 63));
+
+    unset!(in bitmap, [0..4]);
+    assert_eq!(bitmap, !((1 << 63) | 0b_1111));
+
+    let mut regs: [u16; 2] = [u16::MAX; 2];
+    unset!(in regs[1], rev [start = 0, count = 4]);
+    assert_eq!(regs[1], !(0b_1111 << 12));
+}
+
+#[test]
+fn unset_mixed_selectors() {
+    let mut ctrl = u32::MAX;
+    unset!(in ctrl, u32, [0, 4..8, rev 0, start = 12, count = 3]);
+    assert_eq!(ctrl, !((1 << 31) | (0b_111 << 12) | 0b_1111_0001));
+
+    assert_eq!(unset!(0b_1111_1111, u8, rev [0, 4.., rev start = 0, count = 2]), 0b_0011_0000);
+}
+
+#[test]
+fn unset_signed_types() {
+    assert_eq!(unset!((-1), i8, [..4]), 0b_1111_0000_u8 as i8);
+    assert_eq!(unset!((-1), i8, rev [4..]), 0b_1111_0000_u8 as i8);
+    assert_eq!(unset!((-1), i8, rev [0..1]), i8::MAX);
+
+    let mut bitmap: i16 = -1;
+    unset!(in bitmap, rev [1..]);
+    assert_eq!(bitmap, i16::MIN);
+
+    let mut bitmap: i128 = -1;
+    unset!(in bitmap, [..127]);
+    assert_eq!(bitmap, i128::MIN);
+}
+
+#[test]
+fn unset_empty_ranges() {
+    let (start, end) = (5, 5);
+    let mut bitmap: i16 = -1;
+
+    unset!(in bitmap, [start..end]);
+    unset!(in bitmap, i16, [16..]);
+    unset!(in bitmap, rev [..0]);
+    unset!(in bitmap, [start = 16, count = 0]);
+    assert_eq!(bitmap, -1);
+}
+
+#[test]
+fn unset_stepped_ranges() {
+    let mut bitmap: u8 = 0b_1111_1111;
+    unset!(in bitmap, u8, [1..8; step = 2]);
+    assert_eq!(bitmap, 0b_0101_0101);
+
+    assert_eq!(unset!(0b_1111_1111, u8, rev [start = 0, count = 2, step = 3]), 0b_0110_1111);
+}
+
+#[test]
+fn unset_inclusive_ranges() {
+    let mut bitmap: u8 = 0b_1111_1111;
+    unset!(in bitmap, [..=3]);
+    assert_eq!(bitmap, 0b_1111_0000);
+
+    assert_eq!(unset!(0b_1111_1111, u8, rev [0..=7]), 0);
+}
+
+#[test]
+fn unset_in_returns_previous() {
+    let mut bitmap: u16 = 0xffff;
+
+    assert_eq!(unset!(in bitmap, u16, [4..8]), 0xffff);
+    assert_eq!(unset!(in bitmap, rev 0), 0x_ff0f);
+    assert_eq!(unset!(in bitmap, 1), 0x_7f0f);
+    assert_eq!(bitmap, 0x_7f0d);
+}
+
+#[test]
+fn unset_in_as_expression() {
+    let mut bitmap: u8 = 0xff;
+    let mask = 0b_0000_0001;
+
+    let prev = match bitmap {
+        0 => 0,
+        _ => unset!(in bitmap, u8, [1..3]),
+    };
+
+    assert_eq!(prev, 0xff);
+    assert_eq!(bitmap & mask, mask);
+}
+
+#[test]
+fn unset_in_position_reads_bitmap() {
+    let mut reg: u32 = 0b_1111;
+    unset!(in reg, u32, (reg & 3));
+    unset!(in reg, [reg.count_ones() - 2..]);
+    assert_eq!(reg, 0b_0001);
+
+    let mut regs: [u8; 2] = [0xff, 5];
+    unset!(in regs[0], u8, regs[1]);
+    unset!(in regs[0], rev regs[1]);
+    assert_eq!(regs[0], 0b_1101_1011);
+}
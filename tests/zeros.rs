@@ -0,0 +1,54 @@
+use bit_fiddler::zeros;
+
+#[test]
+fn zeros_whole_bitmap() {
+    let bitmap: u8 = 0b_1011_0100;
+
+    assert!(zeros!(bitmap).eq([0, 1, 3, 6]));
+    assert!(zeros!(bitmap, u8).eq([0, 1, 3, 6]));
+    assert!(zeros!(bitmap, u8, [..]).eq([0, 1, 3, 6]));
+    assert_eq!(zeros!(0xff, u8).next(), None);
+    assert_eq!(zeros!(0, u32).len(), 32);
+}
+
+#[test]
+fn zeros_signed() {
+    let bitmap: i8 = 0;
+    assert!(zeros!(bitmap).eq(0..8));
+
+    let bitmap: i64 = i64::MAX;
+    assert!(zeros!(bitmap).eq([63]));
+    assert!(zeros!(bitmap, rev [..]).eq([0]));
+}
+
+#[test]
+fn zeros_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert!(zeros!(bitmap, [4..12]).eq([4, 8, 9, 10, 11]));
+    assert!(zeros!(bitmap, u16, [0, 4, 10]).eq([4, 10]));
+    assert!(zeros!(bitmap, [..; step = 2]).eq([4, 8, 10]));
+    assert!(zeros!(bitmap, [12..]).eq([]));
+}
+
+#[test]
+fn zeros_rev_selectors() {
+    let bitmap: u16 = 0b_1111_0000_1110_1111;
+
+    assert!(zeros!(bitmap, rev [..]).eq([4, 5, 6, 7, 11]));
+    assert!(zeros!(bitmap, u16, rev [6..]).eq([6, 7, 11]));
+    assert!(zeros!(bitmap, rev [6..]).rev().eq([11, 7, 6]));
+}
+
+#[test]
+fn zeros_double_ended_and_exact_size() {
+    let bitmap: u8 = 0b_0110_1110;
+
+    let mut iter = zeros!(bitmap, u8);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(7));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.next(), None);
+}